@@ -1,14 +1,12 @@
 use gpui::*;
 
-mod hello;
-
-use hello::HelloWorld;
+use xml2gpui::view::XmlView;
 
 fn main() {
     App::new().run(|cx: &mut AppContext| {
         cx.open_window(WindowOptions::default(), |cx| {
-            // Root view
-            HelloWorld::new(cx)
+            // Root view, re-rendered whenever test.html changes
+            XmlView::new("test.html", cx)
         });
     });
 }
@@ -0,0 +1,18 @@
+// Arbitrary class lists must resolve and lint without panicking
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::format::sort_classes;
+use xml2gpui::lint::check_classes;
+use xml2gpui::style::ResolvedStyle;
+
+fuzz_target!(|classes: &str| {
+    collect_diagnostics(|| {
+        ResolvedStyle::from_classes(classes);
+        check_classes(classes);
+    });
+    let sorted = sort_classes(classes);
+    assert_eq!(sort_classes(&sorted), sorted);
+});
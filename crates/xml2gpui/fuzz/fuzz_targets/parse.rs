@@ -0,0 +1,20 @@
+// Arbitrary markup must parse or fail with an error, and whatever parses must print to markup
+// that parses back to the same tree and formats the same way again
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use xml2gpui::format::format_xml;
+use xml2gpui::tree::{try_parse_xml, try_parse_xml_with_comments};
+
+fuzz_target!(|xml: &str| {
+    if let Ok(root) = try_parse_xml(xml) {
+        let printed = root.to_xml();
+        let reparsed = try_parse_xml(&printed).expect("printed markup parses");
+        assert_eq!(reparsed.to_xml(), printed);
+    }
+    if try_parse_xml_with_comments(xml).is_ok() {
+        let formatted = format_xml(xml).expect("markup that parses formats");
+        assert_eq!(format_xml(&formatted).unwrap(), formatted);
+    }
+});
@@ -0,0 +1,132 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::document::{load_document, normalize_path, Document, FsLoader, XmlLoader};
+use crate::tree::ParseError;
+
+// Entries start with this and the version of the format they are written in, so entries of
+// other versions are ignored. bincode doesn't describe the types it writes: bump FORMAT_VERSION
+// whenever a type in CachedDocument changes how it serializes.
+const MAGIC: &[u8] = b"xml2gpui";
+const FORMAT_VERSION: u32 = 1;
+
+// A document with the hash of each file it was loaded from, so it is only used while the
+// files haven't changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedDocument {
+    pub document: Document,
+    // Hashes of the contents of document.files, in the same order
+    pub hashes: Vec<u64>,
+}
+
+impl CachedDocument {
+    pub fn new(document: Document, loader: &dyn XmlLoader) -> Result<Self, ParseError> {
+        let hashes = document
+            .files
+            .iter()
+            .map(|file| {
+                loader
+                    .load(file)
+                    .map(|xml| hash(xml.as_bytes()))
+                    .map_err(|e| {
+                        ParseError::new(format!("Could not read file: {}", e)).with_file(file)
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(CachedDocument { document, hashes })
+    }
+
+    // Whether every file still has the contents the document was parsed from
+    pub fn is_current(&self, loader: &dyn XmlLoader) -> bool {
+        self.document.files.len() == self.hashes.len()
+            && self
+                .document
+                .files
+                .iter()
+                .zip(&self.hashes)
+                .all(|(file, &expected)| {
+                    loader
+                        .load(file)
+                        .is_ok_and(|xml| hash(xml.as_bytes()) == expected)
+                })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bincode::serialize_into(&mut bytes, &FORMAT_VERSION).unwrap();
+        bincode::serialize_into(&mut bytes, self).unwrap();
+        bytes
+    }
+
+    // None if the bytes are not an entry in the current format
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Cursor::new(bytes.strip_prefix(MAGIC)?);
+        let version: u32 = bincode::deserialize_from(&mut reader).ok()?;
+        if version != FORMAT_VERSION {
+            return None;
+        }
+        bincode::deserialize_from(&mut reader).ok()
+    }
+}
+
+// Like load_document, but reuses the tree parsed on a previous run when neither the file nor
+// its includes have changed since. Entries are stored in `cache_dir`, named by the hash of the
+// path and contents of the file. Old entries are not removed.
+pub fn load_document_cached(
+    path: &Path,
+    loader: &dyn XmlLoader,
+    cache_dir: &Path,
+) -> Result<Document, ParseError> {
+    let path = normalize_path(path);
+    let xml = loader
+        .load(&path)
+        .map_err(|e| ParseError::new(format!("Could not read file: {}", e)).with_file(&path))?;
+    let mut key = path.to_string_lossy().into_owned().into_bytes();
+    key.push(0);
+    key.extend_from_slice(xml.as_bytes());
+    let cache_file = cache_dir.join(format!("{:016x}.bin", hash(&key)));
+
+    if let Some(cached) = std::fs::read(&cache_file)
+        .ok()
+        .and_then(|bytes| CachedDocument::from_bytes(&bytes))
+    {
+        if cached.is_current(loader) {
+            return Ok(cached.document);
+        }
+    }
+
+    let cached = CachedDocument::new(load_document(&path, loader)?, loader)?;
+    // The cache only saves time, the document is fine without it
+    let _ = std::fs::create_dir_all(cache_dir)
+        .and_then(|_| std::fs::write(&cache_file, cached.to_bytes()));
+    Ok(cached.document)
+}
+
+// For build scripts: parses a markup file into $OUT_DIR/<name>.bin, so an app can ship the
+// parsed tree with
+// CachedDocument::from_bytes(include_bytes!(concat!(env!("OUT_DIR"), "/<name>.bin"))).
+// Cargo runs the build script again when the file or one of its includes changes.
+pub fn build(path: impl AsRef<Path>, name: &str) {
+    let out_dir = std::env::var_os("OUT_DIR").expect("cache::build must run in a build script");
+    let document = load_document(path.as_ref(), &FsLoader).unwrap_or_else(|e| panic!("{}", e));
+    for file in &document.files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+
+    let cached = CachedDocument::new(document, &FsLoader).unwrap_or_else(|e| panic!("{}", e));
+    let out_file = Path::new(&out_dir).join(format!("{}.bin", name));
+    std::fs::write(&out_file, cached.to_bytes())
+        .unwrap_or_else(|e| panic!("Could not write {}: {}", out_file.display(), e));
+}
+
+// 64-bit FNV-1a, which unlike std's hashers is the same across Rust versions and platforms
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
@@ -0,0 +1,645 @@
+use std::cell::Cell;
+use std::path::Path;
+
+use crate::classes::tailwind_method;
+use crate::diagnostics::{collect_diagnostics, report, Diagnostic};
+use crate::document::{load_document, FsLoader};
+use crate::grid::{is_grid, GridLayout, GRID_HELPERS};
+use crate::style::{Color, Corner, Declaration, Length, LineHeight, ResolvedStyle, Shadow, Side};
+use crate::template::resolve_templates;
+use crate::text::{build_paragraph, Paragraph};
+use crate::theme::Theme;
+use crate::tree::{
+    element_key, group_children, scroll_axes, ChildGroup, Component, BLOCK_ELEMENTS,
+    CONTROL_ELEMENTS,
+};
+
+const INDENT: &str = "    ";
+// Method chains up to this length stay on one line
+const CHAIN_WIDTH: usize = 60;
+
+// Generates Rust source with a function returning the element tree of the markup, built the
+// same way render_component builds it. Classes are resolved with ResolvedStyle like
+// set_attributes does, and each declaration becomes the code ResolvedStyle::apply runs for
+// it. What needs the runtime (form controls, virtual lists and focus handles for tabindex) is
+// left out with a comment and reported as unsupported.
+pub fn generate(root: &Component, function_name: &str, theme: &Theme) -> String {
+    let generator = Generator {
+        theme,
+        uses_grid: Cell::new(false),
+    };
+    let element = generator.node(root, &mut Vec::new());
+    let uses_style = root.is_inline() || has_paragraphs(root, theme);
+
+    let mut lines = Vec::new();
+    if uses_style {
+        lines.push("let style = cx.text_style();".to_string());
+    }
+    lines.extend(assign("let element = ", element));
+    lines.push("element.into_any_element()".to_string());
+
+    let mut source = String::new();
+    source.push_str("// Generated by xml2gpui, do not edit\n\n");
+    source.push_str("use gpui::*;\n\n");
+    // InteractiveText takes the ranges of links in a Vec, which may have only one
+    source.push_str("#[allow(clippy::single_range_in_vec_init)]\n");
+    source.push_str(&format!(
+        "pub fn {}({}: &mut WindowContext) -> AnyElement {{\n",
+        function_name,
+        if uses_style { "cx" } else { "_cx" }
+    ));
+    for line in indent(lines) {
+        source.push_str(&line);
+        source.push('\n');
+    }
+    source.push_str("}\n");
+    if generator.uses_grid.get() {
+        source.push('\n');
+        source.push_str(GRID_HELPERS);
+    }
+    source
+}
+
+// For build scripts: generates `function_name` from a markup file into
+// $OUT_DIR/<function_name>.rs, to be used with
+// include!(concat!(env!("OUT_DIR"), "/<function_name>.rs")). Cargo runs the build script
+// again when the file or one of its includes changes, and shows what could not be generated as
+// warnings.
+pub fn build(path: impl AsRef<Path>, function_name: &str) {
+    let out_dir = std::env::var_os("OUT_DIR").expect("codegen::build must run in a build script");
+    let document = load_document(path.as_ref(), &FsLoader).unwrap_or_else(|e| panic!("{}", e));
+    for file in &document.files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+
+    let root = resolve_templates(document.root);
+    let (source, diagnostics) =
+        collect_diagnostics(|| generate(&root, function_name, &Theme::default()));
+    for diagnostic in diagnostics {
+        println!("cargo:warning={}", diagnostic);
+    }
+    let out_file = Path::new(&out_dir).join(format!("{}.rs", function_name));
+    std::fs::write(&out_file, source)
+        .unwrap_or_else(|e| panic!("Could not write {}: {}", out_file.display(), e));
+}
+
+struct Generator<'a> {
+    theme: &'a Theme,
+    // Whether the grid helpers have to be generated too
+    uses_grid: Cell<bool>,
+}
+
+impl Generator<'_> {
+    // Expression building the element of a node, mirroring render_node
+    fn node(&self, component: &Component, path: &mut Vec<usize>) -> Vec<String> {
+        match component.elem.as_str() {
+            elem if BLOCK_ELEMENTS.contains(&elem) || elem == "br" => self.block(component, path),
+            elem if CONTROL_ELEMENTS.contains(&elem) || elem == "virtual-list" => {
+                let mut statements = Vec::new();
+                unsupported(
+                    &mut statements,
+                    &format!("<{}> needs the xml2gpui runtime", elem),
+                );
+                let mut lines = vec!["{".to_string()];
+                lines.extend(indent(statements));
+                lines.push(format!("{}div()", INDENT));
+                lines.push("}".to_string());
+                lines
+            }
+            _ if component.is_inline() => {
+                let key = element_key(component, path);
+                match build_paragraph(std::slice::from_ref(component), self.theme) {
+                    Some(paragraph) => paragraph_code(&paragraph, &key),
+                    None => vec!["div()".to_string()],
+                }
+            }
+            "img" => match component.attribute("src") {
+                Some(src) => styled(
+                    format!("img({:?}.to_string())", src),
+                    &ResolvedStyle::from_attributes(&component.attributes),
+                    Vec::new(),
+                    Vec::new(),
+                    false,
+                    Vec::new(),
+                ),
+                None => {
+                    vec!["div().child(\"Error: img element must have src attribute\")".to_string()]
+                }
+            },
+            "svg" => match component.attribute("path") {
+                Some(svg_path) => styled(
+                    format!("svg().path({:?})", svg_path),
+                    &ResolvedStyle::from_attributes(&component.attributes),
+                    Vec::new(),
+                    Vec::new(),
+                    false,
+                    Vec::new(),
+                ),
+                None => {
+                    vec!["div().child(\"Error: svg element must have path attribute\")".to_string()]
+                }
+            },
+            _ => vec!["div()".to_string()],
+        }
+    }
+
+    fn block(&self, component: &Component, path: &mut Vec<usize>) -> Vec<String> {
+        let mut statements = Vec::new();
+        if component.attribute("tabindex").is_some() {
+            unsupported(
+                &mut statements,
+                "tabindex needs a focus handle from the xml2gpui runtime",
+            );
+        }
+
+        // Theme classes go first so that the element's own classes override them
+        let style = ResolvedStyle::resolve(&component.elem, &component.attributes, self.theme);
+        let mut calls = Vec::new();
+        let children = if is_grid(component) {
+            // Gap classes on the grid separate both the rows and the cells within a row
+            statements.push("let column_gap = element.style().gap.width;".to_string());
+            calls.push(".flex()".to_string());
+            calls.push(".flex_col()".to_string());
+            self.grid_rows(component, path)
+        } else {
+            self.children(component, path)
+        };
+
+        let scroll = scroll_axes(component);
+        if component.attribute("id").is_some() || scroll.is_some() {
+            calls.push(format!(".id({:?})", element_key(component, path)));
+        }
+        match scroll {
+            Some((true, true)) => calls.push(".overflow_scroll()".to_string()),
+            Some((true, false)) => calls.push(".overflow_x_scroll()".to_string()),
+            Some(_) => calls.push(".overflow_y_scroll()".to_string()),
+            None => {}
+        }
+
+        styled(
+            "div()".to_string(),
+            &style,
+            calls,
+            statements,
+            has_paragraphs(component, self.theme),
+            children,
+        )
+    }
+
+    // Expressions of the rows of a grid, mirroring render_grid
+    fn grid_rows(&self, component: &Component, path: &mut Vec<usize>) -> Vec<Vec<String>> {
+        self.uses_grid.set(true);
+        let layout = GridLayout::new(component);
+        let mut rows = Vec::new();
+        for cells in &layout.rows {
+            let mut calls = Vec::new();
+            for &(i, span) in cells {
+                path.push(i);
+                let cell = self.node(&component.children[i], path);
+                path.pop();
+                let cell = chain(
+                    format!("grid_cell({}, column_gap)", span),
+                    vec![wrap(".child(", cell, ")")],
+                );
+                calls.push(wrap(".child(", cell, ")"));
+            }
+            if let Some(span) = layout.remaining(cells) {
+                calls.push(vec![format!(".child(grid_cell({}, column_gap))", span)]);
+            }
+            let base = format!("grid_row(column_gap, {})", layout.stretch_rows);
+            rows.push(chain(base, calls));
+        }
+        rows
+    }
+
+    // Expressions of the children, mirroring render_children
+    fn children(&self, component: &Component, path: &mut Vec<usize>) -> Vec<Vec<String>> {
+        let mut expressions = Vec::new();
+        for group in group_children(component) {
+            match group {
+                ChildGroup::Inline(nodes) => {
+                    let (first, first_node) = nodes[0];
+                    path.push(first);
+                    let id = element_key(first_node, path);
+                    path.pop();
+                    let paragraph =
+                        build_paragraph(nodes.iter().map(|(_, node)| *node), self.theme);
+                    if let Some(paragraph) = paragraph {
+                        expressions.push(paragraph_code(&paragraph, &id));
+                        continue;
+                    }
+                    for (j, node) in nodes.into_iter().filter(|(_, node)| node.elem == "br") {
+                        path.push(j);
+                        expressions.push(self.node(node, path));
+                        path.pop();
+                    }
+                }
+                ChildGroup::Block(i, node, marker) => {
+                    path.push(i);
+                    let mut child = self.node(node, path);
+                    path.pop();
+
+                    if let Some(marker) = marker {
+                        let calls = vec![
+                            vec![".flex()".to_string()],
+                            vec![".flex_row()".to_string()],
+                            vec![format!(
+                                ".child(div().flex_none().w_6().child({:?}))",
+                                marker
+                            )],
+                            wrap(".child(", child, ")"),
+                        ];
+                        child = chain("div()".to_string(), calls);
+                    }
+                    expressions.push(child);
+                }
+            }
+        }
+
+        if let Some(text) = &component.text {
+            expressions.push(vec![format!("{:?}", text)]);
+        }
+        expressions
+    }
+}
+
+// Element expression with the style applied, then the element calls made and the children
+// added. Declarations that need statements turn it into a block that builds the element in a
+// variable.
+fn styled(
+    base: String,
+    style: &ResolvedStyle,
+    element_calls: Vec<String>,
+    mut statements: Vec<String>,
+    refine_style: bool,
+    children: Vec<Vec<String>>,
+) -> Vec<String> {
+    let (style_calls, style_statements) = style_code(style);
+    // The element's own statements, like reading the gap of a grid, see the styled element
+    statements.splice(0..0, style_statements);
+
+    let mut calls: Vec<Vec<String>> = style_calls.into_iter().map(|call| vec![call]).collect();
+    let mut final_calls: Vec<Vec<String>> =
+        element_calls.into_iter().map(|call| vec![call]).collect();
+    final_calls.extend(
+        children
+            .into_iter()
+            .map(|child| wrap(".child(", child, ")")),
+    );
+
+    if statements.is_empty() && !refine_style {
+        calls.extend(final_calls);
+        return chain(base, calls);
+    }
+
+    let mutable = refine_style || statements.iter().any(|s| !s.starts_with("//"));
+    let mut body = assign(
+        if mutable {
+            "let mut element = "
+        } else {
+            "let element = "
+        },
+        chain(base, calls),
+    );
+    body.extend(statements);
+    if refine_style {
+        // Paragraphs are built with the text style of their ancestors, like in styled_block
+        body.push("let mut style = style.clone();".to_string());
+        body.push("if let Some(refinement) = element.text_style().clone() {".to_string());
+        body.push(format!("{}style.refine(&refinement);", INDENT));
+        body.push("}".to_string());
+    }
+    body.extend(chain("element".to_string(), final_calls));
+
+    let mut block = vec!["{".to_string()];
+    block.extend(indent(body));
+    block.push("}".to_string());
+    block
+}
+
+fn unsupported(statements: &mut Vec<String>, message: &str) {
+    report(Diagnostic::Unsupported(message.to_string()));
+    statements.push(format!("// {}", message));
+}
+
+// Method calls and statements applying a style, mirroring ResolvedStyle::apply. Once a
+// declaration needs a statement, the ones after it are statements too, to keep their order.
+fn style_code(style: &ResolvedStyle) -> (Vec<String>, Vec<String>) {
+    let mut calls = Vec::new();
+    let mut statements = Vec::new();
+    if let Some(font) = &style.font {
+        calls.push(format!(".font({:?})", font));
+    }
+    for declaration in &style.declarations {
+        match declaration_code(declaration) {
+            DeclarationCode::Call(call) if statements.is_empty() => calls.push(call),
+            DeclarationCode::Call(call) => statements.push(format!("element = element{};", call)),
+            DeclarationCode::Statements(lines) => statements.extend(lines),
+        }
+    }
+    if let Some(color) = style.shadow_color {
+        statements.push("if let Some(shadows) = &mut element.style().box_shadow {".to_string());
+        statements.push(format!("{}for shadow in shadows.iter_mut() {{", INDENT));
+        statements.push(format!(
+            "{}{}shadow.color = {};",
+            INDENT,
+            INDENT,
+            color_code(color)
+        ));
+        statements.push(format!("{}}}", INDENT));
+        statements.push("}".to_string());
+    }
+    (calls, statements)
+}
+
+enum DeclarationCode {
+    Call(String),
+    Statements(Vec<String>),
+}
+
+fn declaration_code(declaration: &Declaration) -> DeclarationCode {
+    let call = |method: &str, argument: String| {
+        DeclarationCode::Call(format!(".{}({})", method, argument))
+    };
+    let statement = |statement: String| DeclarationCode::Statements(vec![statement]);
+    let text_style = |field: &str, value: &str| {
+        statement(format!(
+            "element.text_style().get_or_insert_with(Default::default).{} = {};",
+            field, value
+        ))
+    };
+    match declaration {
+        // Only classes of the table are resolved to Declaration::Class
+        Declaration::Class(class_name) => match tailwind_method(class_name) {
+            Some(method) => DeclarationCode::Call(format!(".{}", method)),
+            None => DeclarationCode::Statements(Vec::new()),
+        },
+        Declaration::Background(color) => call("bg", rgba_code(*color)),
+        Declaration::TextColor(color) => call("text_color", rgba_code(*color)),
+        Declaration::BorderColor(color) => call("border_color", rgba_code(*color)),
+        Declaration::Rounded(corner, length) => {
+            let method = match corner {
+                Corner::All => "rounded",
+                Corner::Top => "rounded_t",
+                Corner::Right => "rounded_r",
+                Corner::Bottom => "rounded_b",
+                Corner::Left => "rounded_l",
+                Corner::TopLeft => "rounded_tl",
+                Corner::TopRight => "rounded_tr",
+                Corner::BottomRight => "rounded_br",
+                Corner::BottomLeft => "rounded_bl",
+            };
+            call(method, length_code(*length))
+        }
+        Declaration::BorderWidth(side, length) => {
+            let method = match side {
+                Side::Top => "border_t_width",
+                Side::Right => "border_r_width",
+                Side::Bottom => "border_b_width",
+                Side::Left => "border_l_width",
+            };
+            call(method, length_code(*length))
+        }
+        Declaration::Top(length) => call("top", length_code(*length)),
+        Declaration::Left(length) => call("left", length_code(*length)),
+        Declaration::Width(length) => call("w", length_code(*length)),
+        Declaration::Height(length) => call("h", length_code(*length)),
+        Declaration::Opacity(opacity) => {
+            statement(format!("element.style().opacity = Some({:?});", opacity))
+        }
+        Declaration::Hidden => statement("element.style().display = Some(Display::None);".into()),
+        Declaration::Shadows(shadows) => DeclarationCode::Statements(shadows_code(shadows)),
+        Declaration::FontSize(size) => {
+            text_style("font_size", &format!("Some(rems({:?}).into())", size))
+        }
+        Declaration::LineHeight(LineHeight::Relative(height)) => {
+            text_style("line_height", &format!("Some(relative({:?}))", height))
+        }
+        Declaration::LineHeight(LineHeight::Rems(height)) => {
+            text_style("line_height", &format!("Some(rems({:?}).into())", height))
+        }
+        Declaration::Italic(true) => text_style("font_style", "Some(FontStyle::Italic)"),
+        Declaration::Italic(false) => text_style("font_style", "Some(FontStyle::Normal)"),
+        Declaration::Underline(true) => text_style(
+            "underline",
+            "Some(UnderlineStyle { thickness: px(1.), ..Default::default() })",
+        ),
+        Declaration::Underline(false) => text_style("underline", "None"),
+        Declaration::Nowrap(true) => text_style("white_space", "Some(WhiteSpace::Nowrap)"),
+        Declaration::Nowrap(false) => text_style("white_space", "Some(WhiteSpace::Normal)"),
+        Declaration::Truncate => {
+            let DeclarationCode::Statements(mut lines) =
+                text_style("white_space", "Some(WhiteSpace::Nowrap)")
+            else {
+                unreachable!()
+            };
+            lines.push("element.style().overflow.x = Some(Overflow::Hidden);".to_string());
+            DeclarationCode::Statements(lines)
+        }
+    }
+}
+
+fn shadows_code(shadows: &[Shadow]) -> Vec<String> {
+    if shadows.is_empty() {
+        return vec!["element.style().box_shadow = Some(Default::default());".to_string()];
+    }
+    // Collected, as GPUI keeps the shadows in a SmallVec
+    let mut lines = vec![
+        "element.style().box_shadow = Some(".to_string(),
+        format!("{}[", INDENT),
+    ];
+    for shadow in shadows {
+        let fields = vec![
+            format!("color: {},", color_code(shadow.color)),
+            format!("offset: point(px({:?}), px({:?})),", shadow.x, shadow.y),
+            format!("blur_radius: px({:?}),", shadow.blur),
+            format!("spread_radius: px({:?}),", shadow.spread),
+        ];
+        lines.push(format!("{}BoxShadow {{", INDENT.repeat(2)));
+        lines.extend(indent(indent(indent(fields))));
+        lines.push(format!("{}}},", INDENT.repeat(2)));
+    }
+    lines.push(format!("{}]", INDENT));
+    lines.push(format!("{}.into_iter()", INDENT));
+    lines.push(format!("{}.collect(),", INDENT));
+    lines.push(");".to_string());
+    lines
+}
+
+fn rgba_code(color: Color) -> String {
+    format!("rgba(0x{:08x})", color.0)
+}
+
+// A color as an Hsla field
+fn color_code(color: Color) -> String {
+    format!("{}.into()", rgba_code(color))
+}
+
+fn length_code(length: Length) -> String {
+    match length {
+        Length::Pixels(value) => format!("px({:?})", value),
+        Length::Rems(value) => format!("rems({:?})", value),
+    }
+}
+
+// Whether the element has paragraphs of inline content below it, which are built with the
+// inherited text style
+fn has_paragraphs(component: &Component, theme: &Theme) -> bool {
+    // Paragraphs are only laid out here to find out whether there are any
+    let (has_paragraphs, _) = collect_diagnostics(|| {
+        component.children.iter().any(|child| {
+            if child.is_inline() {
+                build_paragraph(std::slice::from_ref(child), theme).is_some()
+            } else {
+                has_paragraphs(child, theme)
+            }
+        })
+    });
+    has_paragraphs
+}
+
+// Expression of a paragraph, mirroring render_inline
+fn paragraph_code(paragraph: &Paragraph, id: &str) -> Vec<String> {
+    let mut text = vec![format!(
+        "StyledText::new({:?}).with_runs(vec![",
+        paragraph.text
+    )];
+    for (len, style) in &paragraph.runs {
+        if *style == ResolvedStyle::default() {
+            text.push(format!("{}style.to_run({}),", INDENT, len));
+            continue;
+        }
+        text.push(format!("{}{{", INDENT));
+        text.extend(indent(indent(run_style_code(style))));
+        text.push(format!("{}}}.to_run({}),", INDENT, len));
+    }
+    text.push("])".to_string());
+
+    if paragraph.links.is_empty() {
+        return text;
+    }
+    let ranges: Vec<String> = paragraph
+        .links
+        .iter()
+        .map(|(range, _)| format!("{:?}", range))
+        .collect();
+    let urls: Vec<String> = paragraph
+        .links
+        .iter()
+        .map(|(_, url)| format!("{:?}", url))
+        .collect();
+
+    let mut lines = vec!["InteractiveText::new(".to_string()];
+    lines.push(format!("{}{:?},", INDENT, id));
+    push_suffix(&mut text, ",");
+    lines.extend(indent(text));
+    lines.push(")".to_string());
+    lines.push(format!(
+        ".on_click(vec![{}], |ix, cx| {{",
+        ranges.join(", ")
+    ));
+    lines.push(format!("{}cx.open_url([{}][ix]);", INDENT, urls.join(", ")));
+    lines.push("})".to_string());
+    lines
+}
+
+// Statements and expression of the text style of a run, mirroring run_style
+fn run_style_code(style: &ResolvedStyle) -> Vec<String> {
+    let element = styled(
+        "div()".to_string(),
+        style,
+        Vec::new(),
+        Vec::new(),
+        false,
+        Vec::new(),
+    );
+    let mut lines = vec!["let mut style = style.clone();".to_string()];
+    lines.extend(assign("let mut element = ", element));
+    lines.push("if let Some(refinement) = element.text_style().clone() {".to_string());
+    lines.push(format!("{}style.refine(&refinement);", INDENT));
+    lines.push("}".to_string());
+    let background = style
+        .declarations
+        .iter()
+        .any(|declaration| matches!(declaration, Declaration::Background(_)));
+    if background {
+        lines.push("if let Some(Fill::Color(color)) = element.style().background {".to_string());
+        lines.push(format!("{}style.background_color = Some(color);", INDENT));
+        lines.push("}".to_string());
+    }
+    lines.push("style".to_string());
+    lines
+}
+
+fn indent(lines: Vec<String>) -> Vec<String> {
+    lines
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                line
+            } else {
+                format!("{}{}", INDENT, line)
+            }
+        })
+        .collect()
+}
+
+// Method calls on an expression, on one line if they fit or else one call per line
+fn chain(base: String, calls: Vec<Vec<String>>) -> Vec<String> {
+    if calls.iter().all(|call| call.len() == 1) {
+        let line = calls
+            .iter()
+            .fold(base.clone(), |line, call| line + &call[0]);
+        if line.len() <= CHAIN_WIDTH {
+            return vec![line];
+        }
+    }
+    let mut lines = vec![base];
+    for call in calls {
+        lines.extend(indent(call));
+    }
+    lines
+}
+
+// An expression as the argument of a call: on one line if it fits, otherwise as an indented
+// block of lines
+fn wrap(prefix: &str, mut lines: Vec<String>, suffix: &str) -> Vec<String> {
+    // One line, a block or a vec![...] hug the parentheses of the call
+    let hugs = match (lines.first(), lines.last()) {
+        (Some(first), Some(last)) => {
+            lines.len() == 1
+                || (first == "{" && last == "}")
+                || (first.ends_with("vec![") && last == "])")
+        }
+        _ => false,
+    };
+    if hugs {
+        lines[0] = format!("{}{}", prefix, lines[0]);
+        push_suffix(&mut lines, suffix);
+        return lines;
+    }
+    let mut wrapped = vec![prefix.to_string()];
+    let mut inner = indent(lines);
+    push_suffix(&mut inner, ",");
+    wrapped.extend(inner);
+    wrapped.push(suffix.to_string());
+    wrapped
+}
+
+// `let x = <expression>;`, with the expression starting on the same line
+fn assign(prefix: &str, lines: Vec<String>) -> Vec<String> {
+    let mut lines = lines;
+    match lines.first_mut() {
+        Some(first) => *first = format!("{}{}", prefix, first),
+        None => lines.push(prefix.to_string()),
+    }
+    push_suffix(&mut lines, ";");
+    lines
+}
+
+fn push_suffix(lines: &mut Vec<String>, suffix: &str) {
+    match lines.last_mut() {
+        Some(last) => last.push_str(suffix),
+        None => lines.push(suffix.to_string()),
+    }
+}
@@ -0,0 +1,62 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+
+// Problems found while resolving classes and rendering. They are printed, unless collected with
+// collect_diagnostics, which is how the linter finds out what the renderer would complain about.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Diagnostic {
+    UnrecognizedClass(String),
+    // The class is known, but the pinned GPUI revision can't render it (fully)
+    Unsupported(String),
+    // Arbitrary value like shadow-[...] that could not be parsed
+    InvalidValue(String),
+    // Element that can't be rendered as written, e.g. a template that uses itself
+    Element(String),
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::UnrecognizedClass(class_name) => {
+                write!(f, "Unrecognized class: {}", class_name)
+            }
+            Diagnostic::Unsupported(message) => write!(f, "{}", message),
+            Diagnostic::InvalidValue(class_name) => write!(f, "Invalid value: {}", class_name),
+            Diagnostic::Element(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+thread_local! {
+    static COLLECTED: RefCell<Option<Vec<Diagnostic>>> = const { RefCell::new(None) };
+    // Diagnostics printed so far. Rendering runs every frame and would repeat them otherwise.
+    static PRINTED: RefCell<HashSet<Diagnostic>> = RefCell::new(HashSet::new());
+}
+
+pub fn report(diagnostic: Diagnostic) {
+    let collected = COLLECTED.with(|collected| match collected.borrow_mut().as_mut() {
+        Some(diagnostics) => {
+            diagnostics.push(diagnostic.clone());
+            true
+        }
+        None => false,
+    });
+    if !collected && PRINTED.with(|printed| printed.borrow_mut().insert(diagnostic.clone())) {
+        eprintln!("{}", diagnostic);
+    }
+}
+
+// Lets diagnostics that were printed before be printed again, e.g. after the markup was
+// reloaded, so that the problems of the new version are all shown
+pub fn forget_printed() {
+    PRINTED.with(|printed| printed.borrow_mut().clear());
+}
+
+// Runs `f` and returns the diagnostics reported meanwhile instead of printing them
+pub fn collect_diagnostics<R>(f: impl FnOnce() -> R) -> (R, Vec<Diagnostic>) {
+    let previous = COLLECTED.with(|collected| collected.replace(Some(Vec::new())));
+    let result = f();
+    let diagnostics = COLLECTED.with(|collected| collected.replace(previous));
+    (result, diagnostics.unwrap_or_default())
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use crate::tree::Component;
+
+// Paths are child indices from the root. Paths of removed nodes refer to the old tree,
+// everything else refers to the new tree (and, for moves, `from` to the old one).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Inserted {
+        path: Vec<usize>,
+    },
+    Removed {
+        path: Vec<usize>,
+    },
+    Moved {
+        from: Vec<usize>,
+        to: Vec<usize>,
+    },
+    AttributeChanged {
+        path: Vec<usize>,
+        name: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+    TextChanged {
+        path: Vec<usize>,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+// Reports how `new` differs from `old`. Children are matched by their id attribute when they
+// have one, otherwise by position and element name. Matched nodes are compared recursively,
+// unmatched ones are reported as removed or inserted as a whole.
+pub fn diff(old: &Component, new: &Component) -> Vec<Change> {
+    let mut changes = Vec::new();
+    if old.elem == new.elem {
+        diff_node(old, new, &mut Vec::new(), &mut Vec::new(), &mut changes);
+    } else {
+        changes.push(Change::Removed { path: vec![] });
+        changes.push(Change::Inserted { path: vec![] });
+    }
+    changes
+}
+
+fn diff_node(
+    old: &Component,
+    new: &Component,
+    old_path: &mut Vec<usize>,
+    new_path: &mut Vec<usize>,
+    changes: &mut Vec<Change>,
+) {
+    // Attributes, in the order they appear in the old element followed by added ones
+    for (name, old_value) in &old.attributes {
+        let new_value = new.attribute(name);
+        if new_value != Some(old_value.as_str()) {
+            changes.push(Change::AttributeChanged {
+                path: new_path.clone(),
+                name: name.clone(),
+                old: Some(old_value.clone()),
+                new: new_value.map(str::to_string),
+            });
+        }
+    }
+    for (name, new_value) in &new.attributes {
+        if old.attribute(name).is_none() {
+            changes.push(Change::AttributeChanged {
+                path: new_path.clone(),
+                name: name.clone(),
+                old: None,
+                new: Some(new_value.clone()),
+            });
+        }
+    }
+
+    if old.text != new.text {
+        changes.push(Change::TextChanged {
+            path: new_path.clone(),
+            old: old.text.clone(),
+            new: new.text.clone(),
+        });
+    }
+
+    let matches = match_children(old, new);
+
+    let mut matched_new = vec![false; new.children.len()];
+    for (old_index, new_index) in matches.iter().enumerate() {
+        match new_index {
+            Some(new_index) => matched_new[*new_index] = true,
+            None => changes.push(Change::Removed {
+                path: child_path(old_path, old_index),
+            }),
+        }
+    }
+    for (new_index, matched) in matched_new.iter().enumerate() {
+        if !matched {
+            changes.push(Change::Inserted {
+                path: child_path(new_path, new_index),
+            });
+        }
+    }
+
+    // Matched children that kept their relative order are not moves, even if insertions or
+    // removals around them shifted their index
+    let stable = longest_increasing_subsequence(&matches);
+    for (old_index, new_index) in matches.iter().enumerate() {
+        let Some(new_index) = *new_index else {
+            continue;
+        };
+        if !stable[old_index] {
+            changes.push(Change::Moved {
+                from: child_path(old_path, old_index),
+                to: child_path(new_path, new_index),
+            });
+        }
+
+        old_path.push(old_index);
+        new_path.push(new_index);
+        diff_node(
+            &old.children[old_index],
+            &new.children[new_index],
+            old_path,
+            new_path,
+            changes,
+        );
+        old_path.pop();
+        new_path.pop();
+    }
+}
+
+// For each old child, the index of the new child it corresponds to
+fn match_children(old: &Component, new: &Component) -> Vec<Option<usize>> {
+    let new_ids: HashMap<&str, usize> = new
+        .children
+        .iter()
+        .enumerate()
+        .filter_map(|(i, child)| child.attribute("id").map(|id| (id, i)))
+        .collect();
+
+    let mut used = vec![false; new.children.len()];
+    let mut matches = Vec::with_capacity(old.children.len());
+    for (i, old_child) in old.children.iter().enumerate() {
+        let candidate = match old_child.attribute("id") {
+            Some(id) => new_ids.get(id).copied(),
+            None => Some(i).filter(|&j| {
+                new.children
+                    .get(j)
+                    .is_some_and(|new_child| new_child.attribute("id").is_none())
+            }),
+        };
+        let matched = candidate.filter(|&j| !used[j] && new.children[j].elem == old_child.elem);
+        if let Some(j) = matched {
+            used[j] = true;
+        }
+        matches.push(matched);
+    }
+    matches
+}
+
+// Marks the old children in the longest run of matches whose new indices are increasing
+fn longest_increasing_subsequence(matches: &[Option<usize>]) -> Vec<bool> {
+    let items: Vec<(usize, usize)> = matches
+        .iter()
+        .enumerate()
+        .filter_map(|(old_index, new_index)| new_index.map(|new_index| (old_index, new_index)))
+        .collect();
+
+    // tails[k] is the index into `items` ending the best subsequence of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; items.len()];
+    for (i, &(_, new_index)) in items.iter().enumerate() {
+        let position = tails.partition_point(|&t| items[t].1 < new_index);
+        if position > 0 {
+            previous[i] = Some(tails[position - 1]);
+        }
+        if position == tails.len() {
+            tails.push(i);
+        } else {
+            tails[position] = i;
+        }
+    }
+
+    let mut stable = vec![false; matches.len()];
+    let mut current = tails.last().copied();
+    while let Some(i) = current {
+        stable[items[i].0] = true;
+        current = previous[i];
+    }
+    stable
+}
+
+fn child_path(path: &[usize], index: usize) -> Vec<usize> {
+    let mut path = path.to_vec();
+    path.push(index);
+    path
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+use crate::tree::{try_parse_xml, Component, ParseError};
+
+// Source of markup files, so documents can be read from disk or from resources embedded
+// in the binary
+pub trait XmlLoader: Send + Sync {
+    fn load(&self, path: &Path) -> io::Result<String>;
+
+    // Whether the paths are files on disk, which hot reloading can watch for changes
+    fn is_watchable(&self) -> bool {
+        false
+    }
+}
+
+pub struct FsLoader;
+
+impl XmlLoader for FsLoader {
+    fn load(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn is_watchable(&self) -> bool {
+        true
+    }
+}
+
+// Loader for markup compiled into the binary, e.g. with include_str!
+#[derive(Default)]
+pub struct EmbeddedLoader {
+    files: HashMap<PathBuf, &'static str>,
+}
+
+impl EmbeddedLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_file(mut self, path: impl AsRef<Path>, contents: &'static str) -> Self {
+        self.files.insert(normalize_path(path.as_ref()), contents);
+        self
+    }
+}
+
+impl XmlLoader for EmbeddedLoader {
+    fn load(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .get(&normalize_path(path))
+            .map(|contents| contents.to_string())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no embedded file"))
+    }
+}
+
+// A parsed markup file with all of its <include src="..."/> elements resolved
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Document {
+    pub root: Component,
+    // The loaded file followed by every file it includes, directly or indirectly
+    pub files: Vec<PathBuf>,
+}
+
+// Loads a markup file and replaces each <include src="..."/> with the root element of the
+// referenced file, resolved relative to the including file. An included file whose root is
+// <fragment> contributes its children instead, so it can hold several sibling elements.
+pub fn load_document(path: &Path, loader: &dyn XmlLoader) -> Result<Document, ParseError> {
+    let mut resolver = IncludeResolver {
+        loader,
+        stack: Vec::new(),
+        files: Vec::new(),
+    };
+    let mut root = resolver.load_file(&normalize_path(path))?;
+    // Nothing to contribute the children to, so they stay together in a div
+    if root.elem == "fragment" {
+        root.elem = "div".to_string();
+    }
+
+    Ok(Document {
+        root,
+        files: resolver.files,
+    })
+}
+
+struct IncludeResolver<'a> {
+    loader: &'a dyn XmlLoader,
+    // Files currently being loaded, to detect include cycles
+    stack: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+}
+
+impl IncludeResolver<'_> {
+    fn load_file(&mut self, path: &Path) -> Result<Component, ParseError> {
+        if self.stack.iter().any(|p| p == path) {
+            let cycle = self
+                .stack
+                .iter()
+                .chain(std::iter::once(&path.to_path_buf()))
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let including = self.stack.last().unwrap().clone();
+            return Err(ParseError::new(format!("Include cycle: {}", cycle)).with_file(including));
+        }
+
+        let xml = self
+            .loader
+            .load(path)
+            .map_err(|e| ParseError::new(format!("Could not read file: {}", e)).with_file(path))?;
+        if !self.files.iter().any(|p| p == path) {
+            self.files.push(path.to_path_buf());
+        }
+
+        let mut root = try_parse_xml(&xml).map_err(|e| e.with_file(path))?;
+
+        self.stack.push(path.to_path_buf());
+        // A file that is only an include stands for the included file
+        if root.elem == "include" {
+            root = self.load_include(&root, path)?;
+        } else {
+            self.resolve_includes(&mut root, path)?;
+        }
+        self.stack.pop();
+
+        Ok(root)
+    }
+
+    fn resolve_includes(
+        &mut self,
+        component: &mut Component,
+        file: &Path,
+    ) -> Result<(), ParseError> {
+        let children = std::mem::take(&mut component.children);
+        for mut child in children {
+            if child.elem == "include" {
+                let included = self.load_include(&child, file)?;
+                if included.elem == "fragment" {
+                    component.children.extend(included.children);
+                } else {
+                    component.children.push(included);
+                }
+            } else {
+                self.resolve_includes(&mut child, file)?;
+                component.children.push(child);
+            }
+        }
+        Ok(())
+    }
+
+    // Root element of the file an <include> in `file` refers to
+    fn load_include(&mut self, include: &Component, file: &Path) -> Result<Component, ParseError> {
+        let src = include.attribute("src").ok_or_else(|| {
+            ParseError::new("include element must have src attribute").with_file(file)
+        })?;
+        let dir = file.parent().unwrap_or(Path::new(""));
+        self.load_file(&normalize_path(&dir.join(src)))
+    }
+}
+
+// Resolves "." and ".." lexically, so the same file reached through different relative paths
+// is recognized (also for loaders that don't touch the file system)
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => match normalized.components().next_back() {
+                Some(PathComponent::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(PathComponent::RootDir) | Some(PathComponent::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -0,0 +1,134 @@
+use crate::tree::Component;
+
+// Changes to a tree in place, e.g. to update the tree of an XmlView with XmlView::update_tree
+// instead of rewriting and re-parsing its markup. Nodes are addressed by their id attribute or
+// by their path, the child indices from the root (see Component::query_paths).
+impl Component {
+    // Replaces the value of the attribute, or adds it after the others
+    pub fn set_attribute(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.attributes.iter_mut().find(|(k, _)| k == name) {
+            Some((_, v)) => *v = value,
+            None => self.attributes.push((name.to_string(), value)),
+        }
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) -> Option<String> {
+        let index = self.attributes.iter().position(|(k, _)| k == name)?;
+        Some(self.attributes.remove(index).1)
+    }
+
+    pub fn has_class(&self, class_name: &str) -> bool {
+        self.attribute("class")
+            .unwrap_or_default()
+            .split_whitespace()
+            .any(|c| c == class_name)
+    }
+
+    // Appends the class, unless the element has it already. Classes apply in order, so to
+    // override an earlier class, remove it first.
+    pub fn add_class(&mut self, class_name: &str) {
+        if self.has_class(class_name) {
+            return;
+        }
+        let classes = match self.attribute("class") {
+            Some(classes) if !classes.trim().is_empty() => {
+                format!("{} {}", classes.trim_end(), class_name)
+            }
+            _ => class_name.to_string(),
+        };
+        self.set_attribute("class", classes);
+    }
+
+    // Removes every occurrence of the class, and the class attribute when it becomes empty.
+    // Returns whether the element had the class.
+    pub fn remove_class(&mut self, class_name: &str) -> bool {
+        if !self.has_class(class_name) {
+            return false;
+        }
+        let classes: Vec<&str> = self
+            .attribute("class")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter(|c| *c != class_name)
+            .collect();
+        if classes.is_empty() {
+            self.remove_attribute("class");
+        } else {
+            self.set_attribute("class", classes.join(" "));
+        }
+        true
+    }
+
+    // Replaces the content of the element, children included, with the text
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.children.clear();
+        self.text = Some(text.into());
+    }
+
+    // Inserts a child at `index`, like Vec::insert. Text the element held on its own becomes
+    // its first child, as the content is mixed from now on.
+    pub fn insert_child(&mut self, index: usize, child: Component) {
+        if let Some(text) = self.text.take() {
+            self.children.insert(0, Component::text_node(text));
+        }
+        self.children.insert(index, child);
+    }
+
+    pub fn push_child(&mut self, child: Component) {
+        self.insert_child(self.children.len() + self.text.is_some() as usize, child);
+    }
+
+    // Removes and returns the child at `index`, like Vec::remove
+    pub fn remove_child(&mut self, index: usize) -> Component {
+        self.children.remove(index)
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&Component> {
+        self.at_path(&self.path_of_id(id)?)
+    }
+
+    pub fn find_by_id_mut(&mut self, id: &str) -> Option<&mut Component> {
+        let path = self.path_of_id(id)?;
+        self.at_path_mut(&path)
+    }
+
+    // Path of the first element with the id, in document order
+    pub fn path_of_id(&self, id: &str) -> Option<Vec<usize>> {
+        if self.attribute("id") == Some(id) {
+            return Some(Vec::new());
+        }
+        self.children.iter().enumerate().find_map(|(i, child)| {
+            let mut path = child.path_of_id(id)?;
+            path.insert(0, i);
+            Some(path)
+        })
+    }
+
+    // Inserts `child` so that it ends up at `path`. Returns false, leaving the tree alone, if
+    // the parent doesn't exist or has fewer children than the index.
+    pub fn insert_at(&mut self, path: &[usize], child: Component) -> bool {
+        let Some((&index, parent_path)) = path.split_last() else {
+            return false;
+        };
+        match self.at_path_mut(parent_path) {
+            Some(parent) if index <= parent.children.len() + parent.text.is_some() as usize => {
+                parent.insert_child(index, child);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Removes the node at `path`. The root can't be removed.
+    pub fn remove_at(&mut self, path: &[usize]) -> Option<Component> {
+        let (&index, parent_path) = path.split_last()?;
+        let parent = self.at_path_mut(parent_path)?;
+        (index < parent.children.len()).then(|| parent.remove_child(index))
+    }
+
+    pub fn remove_by_id(&mut self, id: &str) -> Option<Component> {
+        let path = self.path_of_id(id)?;
+        self.remove_at(&path)
+    }
+}
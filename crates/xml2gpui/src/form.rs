@@ -0,0 +1,466 @@
+use gpui::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::diagnostics::{report, Diagnostic};
+use crate::render::{render_children, styled_block, ComponentType, RenderState, ViewHandle};
+use crate::tree::{element_key, Component};
+
+const CHECKED_COLOR: u32 = 0x2563ebff;
+const UNCHECKED_COLOR: u32 = 0xd1d5dbff;
+const PLACEHOLDER_COLOR: u32 = 0x9ca3afff;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Text(String),
+}
+
+impl Value {
+    // Text is false when it is empty, "0" or "false", ignoring case and surrounding whitespace,
+    // so that checked="0" leaves a checkbox unchecked
+    pub fn as_bool(&self) -> bool {
+        match self {
+            Value::Bool(value) => *value,
+            Value::Text(text) => {
+                let text = text.trim();
+                !(text.is_empty() || text == "0" || text.eq_ignore_ascii_case("false"))
+            }
+        }
+    }
+
+    pub fn as_text(&self) -> String {
+        match self {
+            Value::Bool(value) => value.to_string(),
+            Value::Text(text) => text.clone(),
+        }
+    }
+}
+
+// Passed to handlers: the id of the control that fired and its new value, if it has one
+#[derive(Debug, Clone)]
+pub struct FormEvent {
+    pub id: Option<String>,
+    pub value: Option<Value>,
+}
+
+type Handler = Rc<dyn Fn(&FormEvent, &mut WindowContext)>;
+
+// Values that controls are bound to with checked="{{name}}" or value="{{name}}", and the
+// handlers that on-click="name", on-change="name" and on-submit="name" route events to. The
+// host keeps it in a Model so controls can update it from their event handlers.
+#[derive(Default)]
+pub struct FormState {
+    values: HashMap<String, Value>,
+    handlers: HashMap<String, Handler>,
+}
+
+impl FormState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    pub fn set_value(&mut self, name: impl Into<String>, value: Value) {
+        self.values.insert(name.into(), value);
+    }
+
+    pub fn on(
+        &mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&FormEvent, &mut WindowContext) + 'static,
+    ) {
+        self.handlers.insert(name.into(), Rc::new(handler));
+    }
+}
+
+// State of the controls that lives in the RenderState, keyed like the controls themselves:
+// values of controls that aren't bound to a FormState once the user changed them, input
+// cursors and the <select> whose options are showing. Event handlers update it, so it is shared.
+#[derive(Default)]
+pub(crate) struct Controls {
+    values: HashMap<SharedString, Value>,
+    // Character index of the cursor of an input
+    cursors: HashMap<SharedString, usize>,
+    open_select: Option<SharedString>,
+}
+
+impl Controls {
+    pub(crate) fn retain(&mut self, keep: impl Fn(&SharedString) -> bool) {
+        self.values.retain(|key, _| keep(key));
+        self.cursors.retain(|key, _| keep(key));
+        if !self.open_select.as_ref().is_some_and(&keep) {
+            self.open_select = None;
+        }
+    }
+}
+
+// Name of the value an attribute is bound to, for attributes like "{{name}}"
+fn binding(value: &str) -> Option<&str> {
+    value
+        .trim()
+        .strip_prefix("{{")?
+        .strip_suffix("}}")
+        .map(str::trim)
+}
+
+// Everything an event handler of a control needs, cloned into its closures
+#[derive(Clone)]
+struct Control {
+    key: SharedString,
+    form: Option<Model<FormState>>,
+    controls: Rc<RefCell<Controls>>,
+    id: Option<String>,
+    // Name of the value the control writes to
+    binding: Option<String>,
+    // Name of the handler called when the value changes or the control is clicked
+    handler: Option<String>,
+}
+
+impl Control {
+    fn new(
+        component: &Component,
+        key: &SharedString,
+        state: &RenderState,
+        value_attribute: &str,
+        event: &str,
+    ) -> Self {
+        Control {
+            key: key.clone(),
+            form: state.form.clone(),
+            controls: state.controls.clone(),
+            id: component.attribute("id").map(str::to_string),
+            binding: component
+                .attribute(value_attribute)
+                .and_then(binding)
+                .map(str::to_string),
+            handler: component.attribute(event).map(str::to_string),
+        }
+    }
+
+    // Current value: the bound value from the form state, or the value the user gave an
+    // unbound control, or the literal attribute
+    fn value(
+        component: &Component,
+        key: &SharedString,
+        state: &RenderState,
+        attribute: &str,
+        cx: &WindowContext,
+    ) -> Option<Value> {
+        let value = component.attribute(attribute);
+        if let (Some(name), Some(form)) = (value.and_then(binding), &state.form) {
+            return form.read(cx).value(name).cloned();
+        }
+        if let Some(edited) = state.controls.borrow().values.get(key) {
+            return Some(edited.clone());
+        }
+        value
+            .filter(|value| binding(value).is_none())
+            .map(|value| Value::Text(value.to_string()))
+    }
+
+    // Stores the new value and calls the handler
+    fn emit(&self, value: Option<Value>, cx: &mut WindowContext) {
+        if let Some(value) = &value {
+            match (&self.form, &self.binding) {
+                (Some(form), Some(name)) => form.update(cx, |form, cx| {
+                    form.set_value(name.clone(), value.clone());
+                    cx.notify();
+                }),
+                _ => {
+                    self.controls
+                        .borrow_mut()
+                        .values
+                        .insert(self.key.clone(), value.clone());
+                    cx.refresh();
+                }
+            }
+        }
+        let Some(name) = &self.handler else {
+            return;
+        };
+        let handler = self
+            .form
+            .as_ref()
+            .and_then(|form| form.read(cx).handlers.get(name).cloned());
+        match handler {
+            Some(handler) => handler(
+                &FormEvent {
+                    id: self.id.clone(),
+                    value,
+                },
+                cx,
+            ),
+            None if self.form.is_none() => report(Diagnostic::Element(format!(
+                "No form state to handle {}, see XmlView::set_form_state",
+                name
+            ))),
+            None => report(Diagnostic::Element(format!(
+                "No handler registered for {}",
+                name
+            ))),
+        }
+    }
+
+    fn cursor(&self) -> Option<usize> {
+        self.controls.borrow().cursors.get(&self.key).copied()
+    }
+
+    fn set_cursor(&self, cursor: usize) {
+        self.controls
+            .borrow_mut()
+            .cursors
+            .insert(self.key.clone(), cursor);
+    }
+}
+
+// Byte offset of a character index, for editing the text of an input
+fn byte_index(text: &str, cursor: usize) -> usize {
+    text.char_indices()
+        .nth(cursor)
+        .map_or(text.len(), |(i, _)| i)
+}
+
+pub(crate) fn render_control(
+    component: &Component,
+    path: &mut Vec<usize>,
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> ComponentType {
+    let key = SharedString::from(element_key(component, path));
+    let (element, child_style) = styled_block(component, text_style, state);
+
+    match component.elem.as_str() {
+        "button" => {
+            let control = Control::new(component, &key, state, "value", "on-click");
+            let element = render_children(element, component, path, &child_style, state, view, cx);
+            ComponentType::Stateful(
+                element
+                    .id(key)
+                    .on_click(move |_, cx| control.emit(None, cx)),
+            )
+        }
+        "checkbox" | "toggle" => {
+            let checked = Control::value(component, &key, state, "checked", cx)
+                .is_some_and(|value| value.as_bool());
+            let control = Control::new(component, &key, state, "checked", "on-change");
+
+            let indicator = if component.elem == "checkbox" {
+                let indicator = div()
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .size_4()
+                    .mr_2()
+                    .rounded_sm()
+                    .border_1();
+                if checked {
+                    indicator
+                        .bg(rgba(CHECKED_COLOR))
+                        .border_color(rgba(CHECKED_COLOR))
+                        .text_color(rgb(0xffffff))
+                        .child("✓")
+                } else {
+                    indicator.border_color(rgba(UNCHECKED_COLOR))
+                }
+            } else {
+                let knob = div().size_4().rounded_full().bg(rgb(0xffffff));
+                let track = div()
+                    .flex()
+                    .flex_row()
+                    .flex_none()
+                    .items_center()
+                    .w_8()
+                    .p_0p5()
+                    .mr_2()
+                    .rounded_full();
+                if checked {
+                    track.bg(rgba(CHECKED_COLOR)).justify_end().child(knob)
+                } else {
+                    track.bg(rgba(UNCHECKED_COLOR)).justify_start().child(knob)
+                }
+            };
+
+            let element = element.child(indicator);
+            let element = render_children(element, component, path, &child_style, state, view, cx);
+            ComponentType::Stateful(
+                element
+                    .id(key)
+                    .on_click(move |_, cx| control.emit(Some(Value::Bool(!checked)), cx)),
+            )
+        }
+        "input" => {
+            let value = Control::value(component, &key, state, "value", cx)
+                .map(|value| value.as_text())
+                .unwrap_or_default();
+            let control = Control::new(component, &key, state, "value", "on-change");
+            let submit = Control::new(component, &key, state, "value", "on-submit");
+            let focus_handle = state.focus_handle(&key, cx);
+            let focused = focus_handle.is_focused(cx);
+            // At the end until the user moves it, and within the text if that got shorter
+            let length = value.chars().count();
+            let cursor = control.cursor().map_or(length, |cursor| cursor.min(length));
+
+            let content = if value.is_empty() && !focused {
+                let placeholder = component.attribute("placeholder").unwrap_or_default();
+                div()
+                    .text_color(rgba(PLACEHOLDER_COLOR))
+                    .child(placeholder.to_string())
+            } else if focused {
+                let (before, after) = value.split_at(byte_index(&value, cursor));
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .child(before.to_string())
+                    .child(div().w(px(1.)).h_4().bg(child_style.color))
+                    .child(after.to_string())
+            } else {
+                div().child(value.clone())
+            };
+            let element = element.child(content);
+
+            let focus = focus_handle.clone();
+            ComponentType::Any(
+                element
+                    .id(key)
+                    .on_click(move |_, cx| cx.focus(&focus))
+                    .on_key_down(move |event, cx| {
+                        let keystroke = &event.keystroke;
+                        let mut value = value.clone();
+                        let mut cursor = cursor;
+                        let edited = match keystroke.key.as_str() {
+                            "left" => {
+                                cursor = cursor.saturating_sub(1);
+                                false
+                            }
+                            "right" => {
+                                cursor = (cursor + 1).min(length);
+                                false
+                            }
+                            "home" => {
+                                cursor = 0;
+                                false
+                            }
+                            "end" => {
+                                cursor = length;
+                                false
+                            }
+                            "backspace" if cursor > 0 => {
+                                cursor -= 1;
+                                value.remove(byte_index(&value, cursor));
+                                true
+                            }
+                            "delete" if cursor < length => {
+                                value.remove(byte_index(&value, cursor));
+                                true
+                            }
+                            "backspace" | "delete" => return,
+                            "enter" => {
+                                submit.emit(Some(Value::Text(value)), cx);
+                                return;
+                            }
+                            key => {
+                                let text = if let Some(text) = &keystroke.ime_key {
+                                    text.as_str()
+                                } else if key == "space" {
+                                    " "
+                                } else if key.chars().count() == 1
+                                    && !keystroke.modifiers.control
+                                    && !keystroke.modifiers.command
+                                {
+                                    key
+                                } else {
+                                    return;
+                                };
+                                value.insert_str(byte_index(&value, cursor), text);
+                                cursor += text.chars().count();
+                                true
+                            }
+                        };
+                        control.set_cursor(cursor);
+                        if edited {
+                            control.emit(Some(Value::Text(value)), cx);
+                        } else {
+                            cx.refresh();
+                        }
+                    })
+                    .track_focus(&focus_handle)
+                    .into_any_element(),
+            )
+        }
+        "select" => {
+            let value =
+                Control::value(component, &key, state, "value", cx).map(|value| value.as_text());
+            let control = Control::new(component, &key, state, "value", "on-change");
+            let open = state.controls.borrow().open_select.as_ref() == Some(&key);
+
+            // Options are (value, label); an option without a value attribute uses its label
+            let options: Vec<(String, String)> = component
+                .children
+                .iter()
+                .filter(|child| child.elem == "option")
+                .map(|option| {
+                    let label = option.text.clone().unwrap_or_default();
+                    let value = option.attribute("value").unwrap_or(&label).to_string();
+                    (value, label)
+                })
+                .collect();
+            let selected = options
+                .iter()
+                .find(|(option, _)| Some(option) == value.as_ref())
+                .map(|(_, label)| label.clone())
+                .or(value)
+                .unwrap_or_default();
+
+            let toggle = control.clone();
+            let mut element = element.child(
+                div()
+                    .id(SharedString::from(format!("{}/current", key)))
+                    .flex()
+                    .flex_row()
+                    .justify_between()
+                    .px_2()
+                    .py_1()
+                    .child(selected)
+                    .child("▾")
+                    .on_click(move |_, cx| {
+                        let mut controls = toggle.controls.borrow_mut();
+                        controls.open_select = match controls.open_select.take() {
+                            Some(open) if open == toggle.key => None,
+                            _ => Some(toggle.key.clone()),
+                        };
+                        cx.refresh();
+                    }),
+            );
+
+            if open {
+                for (i, (option, label)) in options.into_iter().enumerate() {
+                    let control = control.clone();
+                    element = element.child(
+                        div()
+                            .id(SharedString::from(format!("{}/{}", key, i)))
+                            .px_2()
+                            .py_1()
+                            .child(label)
+                            .on_click(move |_, cx| {
+                                control.controls.borrow_mut().open_select = None;
+                                control.emit(Some(Value::Text(option.clone())), cx);
+                            }),
+                    );
+                }
+            }
+
+            ComponentType::Stateful(element.id(key))
+        }
+        _ => ComponentType::Div(element),
+    }
+}
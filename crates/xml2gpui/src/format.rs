@@ -0,0 +1,215 @@
+use crate::tree::{parse_with_outside_nodes, Component, ParseError, COMMENT_NODE};
+
+const INDENT: &str = "  ";
+
+// Attributes written before the others, which follow in alphabetical order
+const LEADING_ATTRIBUTES: &[&str] = &["id", "name", "class"];
+
+// Class groups in the order Tailwind sorts them, by the CSS property they set. Patterns ending
+// in "-" or "#" match a prefix, the longest matching pattern decides the group. Unlike CSS,
+// classes here apply in the order they are written, so classes setting the same style share a
+// group and keep their relative order.
+const CLASS_ORDER: &[&[&str]] = &[
+    &["visible", "invisible"],
+    &["static", "fixed", "absolute", "relative", "sticky"],
+    &["inset-", "top-", "right-", "bottom-", "left-"],
+    &["z-"],
+    &["order-"],
+    &["col-span-"],
+    &["row-span-"],
+    &["m-", "mx-", "my-", "mt-", "mr-", "mb-", "ml-"],
+    &["line-clamp-"],
+    &["block", "inline-flex", "flex", "grid", "hidden"],
+    // size-* sets both the width and the height
+    &["size-", "h-", "max-h-", "min-h-", "w-", "min-w-", "max-w-"],
+    // flex-1, flex-auto.. set the grow and shrink factors too
+    &[
+        "flex-1",
+        "flex-auto",
+        "flex-initial",
+        "flex-none",
+        "flex-shrink",
+        "flex-shrink-0",
+        "flex-grow",
+    ],
+    &["cursor-"],
+    &["grid-cols-"],
+    &["grid-rows-"],
+    &[
+        "flex-row",
+        "flex-col",
+        "flex-row_reverse",
+        "flex-col_reverse",
+    ],
+    &["flex-wrap", "flex-wrap-reverse", "flex-nowrap"],
+    &["content-"],
+    &["items-"],
+    &["justify-"],
+    &["gap-"],
+    &["space-x-", "space-y-"],
+    // truncate sets the overflow and whitespace
+    &["overflow-", "truncate", "whitespace-"],
+    &["rounded", "rounded-"],
+    &["border", "border-"],
+    &["border-[#"],
+    // Gradients set the background color
+    &["bg-[#", "bg-gradient-to-", "from-", "via-", "to-"],
+    &["p-", "px-", "py-", "pt-", "pr-", "pb-", "pl-"],
+    &["text-left", "text-center", "text-right", "text-justify"],
+    &["font-sans", "font-serif", "font-mono"],
+    &["text-"],
+    &["font-"],
+    &["italic", "not-italic"],
+    &["leading-"],
+    &["tracking-"],
+    &["text-color-"],
+    &["underline", "no-underline", "line-through"],
+    &["opacity-"],
+    &["shadow", "shadow-"],
+    &["shadow-color-"],
+];
+
+impl Component {
+    // Markup of the tree in the canonical format of `xml2gpui fmt`: children of block content on
+    // their own lines, inline content on one line, attributes and classes sorted
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::new();
+        write_node(self, 0, false, &mut xml);
+        xml
+    }
+}
+
+// Formats a markup file, keeping its comments. The XML declaration, a DOCTYPE and comments
+// around the root element are written as they are, each on its own line. CDATA sections
+// become escaped text.
+pub fn format_xml(xml: &str) -> Result<String, ParseError> {
+    let (root, outside) = parse_with_outside_nodes(xml)?;
+    let mut formatted = String::new();
+    for node in &outside.before {
+        formatted.push_str(node);
+        formatted.push('\n');
+    }
+    formatted.push_str(&root.to_xml());
+    formatted.push('\n');
+    for node in &outside.after {
+        formatted.push_str(node);
+        formatted.push('\n');
+    }
+    Ok(formatted)
+}
+
+// Classes sorted in Tailwind order, classes Tailwind doesn't know first like
+// prettier-plugin-tailwindcss does
+pub fn sort_classes(classes: &str) -> String {
+    let mut classes: Vec<&str> = classes.split_whitespace().collect();
+    classes.sort_by_key(|class_name| class_group(class_name));
+    classes.join(" ")
+}
+
+// 1 + index of the group in CLASS_ORDER, 0 for unknown classes
+fn class_group(class_name: &str) -> usize {
+    let mut best: Option<(usize, usize)> = None;
+    for (group, patterns) in CLASS_ORDER.iter().enumerate() {
+        for pattern in patterns.iter() {
+            let matches = if pattern.ends_with('-') || pattern.ends_with('#') {
+                class_name.starts_with(pattern)
+            } else {
+                class_name == *pattern
+            };
+            if matches && best.is_none_or(|(length, _)| pattern.len() > length) {
+                best = Some((pattern.len(), group + 1));
+            }
+        }
+    }
+    best.map_or(0, |(_, group)| group)
+}
+
+// Whitespace between inline nodes is significant, so content with any inline node is written
+// on one line with everything below it
+fn write_node(component: &Component, depth: usize, inline: bool, xml: &mut String) {
+    if component.is_text_node() {
+        xml.push_str(&escape(
+            component.text.as_deref().unwrap_or_default(),
+            false,
+        ));
+        return;
+    }
+    if component.elem == COMMENT_NODE {
+        xml.push_str("<!--");
+        xml.push_str(component.text.as_deref().unwrap_or_default());
+        xml.push_str("-->");
+        return;
+    }
+
+    xml.push('<');
+    xml.push_str(&component.elem);
+    for (name, value) in sorted_attributes(component) {
+        xml.push(' ');
+        xml.push_str(name);
+        xml.push_str("=\"");
+        xml.push_str(&escape(&value, true));
+        xml.push('"');
+    }
+    if component.text.is_none() && component.children.is_empty() {
+        xml.push_str("/>");
+        return;
+    }
+    xml.push('>');
+
+    if let Some(text) = &component.text {
+        xml.push_str(&escape(text, false));
+    }
+    let inline = inline
+        || component.text.is_some()
+        || component.children.iter().any(|child| child.is_inline());
+    for child in &component.children {
+        if !inline {
+            xml.push('\n');
+            xml.push_str(&INDENT.repeat(depth + 1));
+        }
+        write_node(child, depth + 1, inline, xml);
+    }
+    if !inline {
+        xml.push('\n');
+        xml.push_str(&INDENT.repeat(depth));
+    }
+
+    xml.push_str("</");
+    xml.push_str(&component.elem);
+    xml.push('>');
+}
+
+fn sorted_attributes(component: &Component) -> Vec<(&str, String)> {
+    let mut attributes: Vec<(&str, String)> = component
+        .attributes
+        .iter()
+        .map(|(name, value)| match name.as_str() {
+            "class" => (name.as_str(), sort_classes(value)),
+            _ => (name.as_str(), value.clone()),
+        })
+        .collect();
+    attributes.sort_by_key(|(name, _)| {
+        match LEADING_ATTRIBUTES
+            .iter()
+            .position(|leading| leading == name)
+        {
+            Some(i) => (i, ""),
+            None => (LEADING_ATTRIBUTES.len(), *name),
+        }
+    });
+    attributes
+}
+
+fn escape(text: &str, attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' if !attribute => escaped.push_str("&gt;"),
+            '"' if attribute => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
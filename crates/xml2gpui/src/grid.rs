@@ -0,0 +1,152 @@
+#[cfg(feature = "render")]
+use gpui::*;
+
+use crate::diagnostics::{report, Diagnostic};
+#[cfg(feature = "render")]
+use crate::render::{render_node, RenderState, ViewHandle};
+use crate::tree::Component;
+
+// Taffy can lay out grids, but the pinned GPUI revision has no style fields for grid templates
+// or placement, so this is not CSS grid. The grid classes build a column of flex rows instead,
+// with cells sized in whole columns: columns all have the same width whatever their content,
+// a row is as tall as its tallest cell, and cells can't span rows or be placed explicitly.
+pub(crate) fn is_grid(component: &Component) -> bool {
+    classes(component).any(|class_name| class_name == "grid")
+}
+
+// Most rows or columns a grid class can ask for, as each row is an element of its own
+const MAX_TRACKS: usize = 1000;
+
+// Classes handled by render_grid, which set_attributes leaves alone
+pub(crate) fn is_grid_class(class_name: &str) -> bool {
+    class_name == "grid"
+        || ["grid-cols-", "grid-rows-", "col-span-", "row-span-"]
+            .iter()
+            .any(|prefix| class_name.starts_with(prefix))
+}
+
+// Lays out the children of a grid element in rows of grid-cols-N cells. Children with
+// col-span-N take N columns, and a child that doesn't fit on the current row starts a new one.
+// With grid-rows-N there are at least N rows, sharing the height of the grid. The columns of
+// all rows line up, also when cells span several columns across a gap.
+#[cfg(feature = "render")]
+pub(crate) fn render_grid(
+    mut element: Div,
+    component: &Component,
+    path: &mut Vec<usize>,
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> Div {
+    let layout = GridLayout::new(component);
+    // Gap classes on the grid separate both the rows and the cells within a row
+    let column_gap = element.style().gap.width;
+    element = element.flex().flex_col();
+
+    for cells in &layout.rows {
+        let mut row = grid_row(column_gap, layout.stretch_rows);
+        for &(i, span) in cells {
+            path.push(i);
+            let cell = render_node(&component.children[i], path, text_style, state, view, cx);
+            path.pop();
+            row = row.child(grid_cell(span, column_gap).child(cell.into_any_element()));
+        }
+        // Keep the cells of an incomplete row at their column widths
+        if let Some(span) = layout.remaining(cells) {
+            row = row.child(grid_cell(span, column_gap));
+        }
+        element = element.child(row);
+    }
+
+    element
+}
+
+// Which children go into which row of a grid, shared by render_grid and codegen
+pub(crate) struct GridLayout {
+    pub(crate) columns: usize,
+    // With grid-rows-N, the rows share the height of the grid
+    pub(crate) stretch_rows: bool,
+    // Index of the child and the number of columns it spans, for each cell of each row
+    pub(crate) rows: Vec<Vec<(usize, usize)>>,
+}
+
+impl GridLayout {
+    pub(crate) fn new(component: &Component) -> Self {
+        let columns = class_number(component, "grid-cols-").unwrap_or(1).max(1);
+        let min_rows = class_number(component, "grid-rows-");
+
+        let mut rows: Vec<Vec<(usize, usize)>> = Vec::new();
+        let mut used = columns;
+        for (i, child) in component.children.iter().enumerate() {
+            if child.is_text_node() && child.text.as_deref().unwrap_or_default().trim().is_empty() {
+                continue;
+            }
+            if classes(child).any(|class_name| class_name.starts_with("row-span-")) {
+                report(Diagnostic::Unsupported(
+                    "row-span is not supported, grids are laid out as rows of flex cells"
+                        .to_string(),
+                ));
+            }
+            let span = class_number(child, "col-span-")
+                .unwrap_or(1)
+                .clamp(1, columns);
+            if used + span > columns {
+                rows.push(Vec::new());
+                used = 0;
+            }
+            rows.last_mut().unwrap().push((i, span));
+            used += span;
+        }
+        if let Some(min_rows) = min_rows {
+            rows.resize(min_rows.max(rows.len()), Vec::new());
+        }
+
+        GridLayout {
+            columns,
+            stretch_rows: min_rows.is_some(),
+            rows,
+        }
+    }
+
+    // Columns left empty at the end of a row
+    pub(crate) fn remaining(&self, cells: &[(usize, usize)]) -> Option<usize> {
+        let used: usize = cells.iter().map(|(_, span)| span).sum();
+        (used < self.columns).then_some(self.columns - used)
+    }
+}
+
+// grid_row and grid_cell, in a file of their own that generated code gets a copy of as
+// GRID_HELPERS, so they may only depend on GPUI. tests/codegen.rs compiles them with a
+// generated grid.
+#[cfg(feature = "render")]
+include!("grid_helpers.rs");
+pub(crate) const GRID_HELPERS: &str = include_str!("grid_helpers.rs");
+
+fn classes(component: &Component) -> impl Iterator<Item = &str> {
+    component
+        .attribute("class")
+        .unwrap_or_default()
+        .split_whitespace()
+}
+
+// N of the last class like "grid-cols-N", at most MAX_TRACKS. Values that aren't a number are
+// reported and ignored.
+fn class_number(component: &Component, prefix: &str) -> Option<usize> {
+    classes(component)
+        .filter_map(|class_name| {
+            let value = class_name.strip_prefix(prefix)?;
+            match value.parse::<usize>() {
+                Ok(n) if n <= MAX_TRACKS => Some(n),
+                Ok(_) => {
+                    report(Diagnostic::InvalidValue(class_name.to_string()));
+                    Some(MAX_TRACKS)
+                }
+                Err(_) => {
+                    report(Diagnostic::InvalidValue(class_name.to_string()));
+                    None
+                }
+            }
+        })
+        .last()
+}
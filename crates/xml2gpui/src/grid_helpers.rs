@@ -0,0 +1,25 @@
+fn grid_row(column_gap: Option<DefiniteLength>, stretch: bool) -> Div {
+    let mut row = div().flex().flex_row().w_full();
+    row.style().gap.width = column_gap;
+    if stretch {
+        row = row.flex_1();
+    }
+    row
+}
+
+// Every row shares its width out in columns, one unit of flex-grow each. A cell spanning
+// several columns also covers the gaps between them, which it starts out with as its basis.
+fn grid_cell(span: usize, column_gap: Option<DefiniteLength>) -> Div {
+    let gaps = (span - 1) as f32;
+    let basis = match column_gap {
+        Some(DefiniteLength::Absolute(AbsoluteLength::Pixels(gap))) => {
+            px(f32::from(gap) * gaps).into()
+        }
+        Some(DefiniteLength::Absolute(AbsoluteLength::Rems(gap))) => rems(gap.0 * gaps).into(),
+        Some(DefiniteLength::Fraction(gap)) => relative(gap * gaps),
+        None => relative(0.),
+    };
+    let mut cell = div().flex().flex_col().flex_basis(basis).min_w_0();
+    cell.style().flex_grow = Some(span as f32);
+    cell
+}
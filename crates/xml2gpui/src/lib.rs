@@ -1 +1,29 @@
-pub mod tree;
\ No newline at end of file
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod classes;
+pub mod codegen;
+pub mod diagnostics;
+pub mod diff;
+pub mod document;
+pub mod edit;
+#[cfg(feature = "render")]
+pub mod form;
+pub mod format;
+pub mod grid;
+pub mod lint;
+#[cfg(feature = "render")]
+pub mod list;
+pub mod query;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod shadow;
+#[cfg(feature = "test-support")]
+pub mod snapshot;
+pub mod style;
+pub mod template;
+pub mod text;
+pub mod theme;
+pub mod tree;
+pub mod typography;
+#[cfg(feature = "render")]
+pub mod view;
@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::diagnostics::{collect_diagnostics, Diagnostic};
+use crate::style::ResolvedStyle;
+use crate::tree::{Component, Location, BLOCK_ELEMENTS, CONTROL_ELEMENTS, INLINE_ELEMENTS};
+
+// Elements with a meaning of their own besides the block, inline and control elements
+pub const SPECIAL_ELEMENTS: &[&str] = &[
+    "img",
+    "svg",
+    "virtual-list",
+    "option",
+    "template",
+    "slot",
+    "include",
+    "fragment",
+];
+
+// Classes that set the same property, so only one of each group makes sense on an element
+const CONFLICTING_CLASSES: &[&[&str]] = &[
+    &["block", "flex", "inline-flex", "hidden", "grid"],
+    &[
+        "flex-row",
+        "flex-col",
+        "flex-row_reverse",
+        "flex-col_reverse",
+    ],
+    &["flex-wrap", "flex-wrap-reverse", "flex-nowrap"],
+    &["absolute", "relative"],
+    &["visible", "invisible"],
+    &["items-start", "items-end", "items-center"],
+    &[
+        "justify-center",
+        "justify-between",
+        "justify-around",
+        "justify-start",
+        "justify-end",
+    ],
+    &[
+        "text-xs",
+        "text-sm",
+        "text-base",
+        "text-lg",
+        "text-xl",
+        "text-2xl",
+        "text-3xl",
+        "text-4xl",
+        "text-5xl",
+        "text-6xl",
+        "text-7xl",
+        "text-8xl",
+        "text-9xl",
+    ],
+    &[
+        "font-thin",
+        "font-extralight",
+        "font-light",
+        "font-normal",
+        "font-medium",
+        "font-semibold",
+        "font-bold",
+        "font-extrabold",
+        "font-black",
+    ],
+    &["font-sans", "font-serif", "font-mono"],
+    &["italic", "not-italic"],
+    &["underline", "no-underline"],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintMessage {
+    pub severity: Severity,
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl fmt::Display for LintMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location {
+            write!(f, "{}:{}: ", location.line, location.column)?;
+        }
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: {}", severity, self.message)
+    }
+}
+
+// Checks a parsed markup file for elements and classes the renderer doesn't understand.
+// `templates` are the template names usable in the file, which may come from other files.
+pub fn lint(root: &Component, templates: &HashSet<String>) -> Vec<LintMessage> {
+    let mut messages = Vec::new();
+    lint_node(root, templates, &mut messages);
+    messages
+}
+
+fn lint_node(component: &Component, templates: &HashSet<String>, messages: &mut Vec<LintMessage>) {
+    if component.is_text_node() {
+        return;
+    }
+    let mut push = |severity, message: String| {
+        messages.push(LintMessage {
+            severity,
+            message,
+            location: component.location,
+        })
+    };
+
+    let elem = component.elem.as_str();
+    let known = BLOCK_ELEMENTS.contains(&elem)
+        || INLINE_ELEMENTS.contains(&elem)
+        || CONTROL_ELEMENTS.contains(&elem)
+        || SPECIAL_ELEMENTS.contains(&elem)
+        || templates.contains(elem);
+    if !known {
+        push(Severity::Error, format!("Unknown element <{}>", elem));
+    }
+
+    let required = match elem {
+        "img" | "include" => Some("src"),
+        "svg" => Some("path"),
+        "virtual-list" => Some("source"),
+        "template" => Some("name"),
+        _ => None,
+    };
+    if let Some(attribute) = required {
+        if component.attribute(attribute).is_none() {
+            push(
+                Severity::Error,
+                format!("<{}> must have {} attribute", elem, attribute),
+            );
+        }
+    }
+
+    let classes = component.attribute("class").unwrap_or_default();
+    for (severity, message) in check_classes(classes) {
+        push(severity, message);
+    }
+
+    for child in &component.children {
+        lint_node(child, templates, messages);
+    }
+}
+
+// Resolves the classes the same way rendering does and reports what the renderer would
+// complain about, plus classes that override each other
+pub fn check_classes(classes: &str) -> Vec<(Severity, String)> {
+    let mut messages = Vec::new();
+    for class_name in classes.split_whitespace() {
+        // Template bodies may build classes from props, which are only known where it is used
+        if class_name.contains("{{") {
+            continue;
+        }
+        let (_, diagnostics) = collect_diagnostics(|| ResolvedStyle::from_classes(class_name));
+        for diagnostic in diagnostics {
+            let severity = match diagnostic {
+                Diagnostic::UnrecognizedClass(_)
+                | Diagnostic::InvalidValue(_)
+                | Diagnostic::Element(_) => Severity::Error,
+                Diagnostic::Unsupported(_) => Severity::Warning,
+            };
+            messages.push((severity, diagnostic.to_string()));
+        }
+    }
+
+    for group in CONFLICTING_CLASSES {
+        let mut found: Vec<&str> = Vec::new();
+        for class_name in classes.split_whitespace() {
+            if group.contains(&class_name) && !found.contains(&class_name) {
+                found.push(class_name);
+            }
+        }
+        if found.len() > 1 {
+            messages.push((
+                Severity::Warning,
+                format!("Conflicting classes: {}", found.join(" ")),
+            ));
+        }
+    }
+    messages
+}
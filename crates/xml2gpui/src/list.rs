@@ -0,0 +1,93 @@
+use gpui::*;
+
+use std::collections::HashMap;
+
+use crate::render::{render_node, ComponentType, RenderState, ViewHandle};
+use crate::style::ResolvedStyle;
+use crate::template::instantiate;
+use crate::tree::{element_key, Component};
+
+// Rows of a <virtual-list source="name">, supplied by the host. Each row is a set of props
+// that fill the {{placeholders}} of the row template, which is the content of the element.
+pub trait ListSource {
+    fn row_count(&self, cx: &AppContext) -> usize;
+    fn row(&self, index: usize, cx: &AppContext) -> Vec<(String, String)>;
+}
+
+// Renders <virtual-list source="logs" item-height="24"> as a uniform_list, which only builds
+// the rows that are visible. Rows all have the height of the first one (or item-height), and
+// the scroll position is kept in the render state.
+pub(crate) fn render_virtual_list(
+    component: &Component,
+    path: &[usize],
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> ComponentType {
+    let Some(name) = component.attribute("source") else {
+        return ComponentType::Div(
+            div().child("Error: virtual-list element must have source attribute"),
+        );
+    };
+    let Some(row_count) = state.list_source(name).map(|source| source.row_count(cx)) else {
+        return ComponentType::Div(div().child(format!(
+            "Error: no list source registered for {}, see XmlView::set_list_source",
+            name
+        )));
+    };
+
+    let key = SharedString::from(element_key(component, path));
+    let item_height = component
+        .attribute("item-height")
+        .and_then(|height| height.parse::<f32>().ok());
+    let template: Vec<Component> = component
+        .children
+        .iter()
+        .filter(|child| !child.is_text_node())
+        .cloned()
+        .collect();
+    let name = name.to_string();
+    let path = path.to_vec();
+    let text_style = text_style.clone();
+    let scroll_handle = state.list_scroll_handle(&key);
+    let row_view = view.clone();
+
+    let list = view
+        .uniform_list(key, row_count, move |state, range, cx| {
+            let Some(source) = state.list_source(&name) else {
+                return Vec::new();
+            };
+            let mut path = path.clone();
+            range
+                .map(|index| {
+                    let row = source.row(index, cx);
+                    let props: HashMap<&str, &str> =
+                        row.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+
+                    let mut element = div().flex().flex_col().w_full();
+                    if let Some(height) = item_height {
+                        element = element.h(px(height)).overflow_hidden();
+                    }
+                    // Row contents are keyed by the path of the list and the row index, so their
+                    // element state follows the row and not the position on screen, and stays
+                    // across reloads, see ElementKeys
+                    path.push(index);
+                    for (i, node) in template.iter().enumerate() {
+                        let node = instantiate(node, &props, &mut HashMap::new());
+                        path.push(i);
+                        let child =
+                            render_node(&node, &mut path, &text_style, state, &row_view, cx);
+                        element = element.child(child.into_any_element());
+                        path.pop();
+                    }
+                    path.pop();
+                    element.into_any_element()
+                })
+                .collect()
+        })
+        .track_scroll(scroll_handle);
+
+    let style = ResolvedStyle::resolve(&component.elem, &component.attributes, &state.theme);
+    ComponentType::Any(style.apply(list).into_any_element())
+}
@@ -0,0 +1,340 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use crate::tree::Component;
+
+// CSS selector made of compound selectors like `div#list.row[data-kind="x"]` (tag or `*`, ids,
+// classes, and attributes that are present or equal to a value) joined by the descendant
+// (whitespace) and child (`>`) combinators. Characters with a meaning in selectors can be
+// escaped with a backslash, e.g. `.bg-\[\#ffffff\]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    compounds: Vec<Compound>,
+    // combinators[i] joins compounds[i] and compounds[i + 1]
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Compound {
+    elem: Option<String>,
+    classes: Vec<String>,
+    // Ids are matched as [id="..."]
+    attributes: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectorError {
+    pub message: String,
+    // Byte offset in the selector
+    pub position: usize,
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        SelectorParser {
+            selector,
+            chars: selector.char_indices().peekable(),
+        }
+        .parse()
+    }
+
+    // Paths (child indices from the root) of the matching elements, in document order. The
+    // root itself can match, text nodes never do.
+    pub fn paths(&self, root: &Component) -> Vec<Vec<usize>> {
+        let mut paths = Vec::new();
+        self.collect(root, &mut Vec::new(), &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    pub fn matches(&self, component: &Component, ancestors: &[&Component]) -> bool {
+        self.matches_from(self.compounds.len() - 1, component, ancestors)
+    }
+
+    fn collect<'a>(
+        &self,
+        component: &'a Component,
+        ancestors: &mut Vec<&'a Component>,
+        path: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        if self.matches(component, ancestors) {
+            paths.push(path.clone());
+        }
+        ancestors.push(component);
+        for (i, child) in component.children.iter().enumerate() {
+            path.push(i);
+            self.collect(child, ancestors, path, paths);
+            path.pop();
+        }
+        ancestors.pop();
+    }
+
+    // Matches right to left: compounds[index] against the component, the ones before it
+    // against its ancestors
+    fn matches_from(&self, index: usize, component: &Component, ancestors: &[&Component]) -> bool {
+        if !self.compounds[index].matches(component) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+        match self.combinators[index - 1] {
+            Combinator::Child => ancestors
+                .split_last()
+                .is_some_and(|(parent, rest)| self.matches_from(index - 1, parent, rest)),
+            Combinator::Descendant => (0..ancestors.len())
+                .rev()
+                .any(|i| self.matches_from(index - 1, ancestors[i], &ancestors[..i])),
+        }
+    }
+}
+
+impl std::str::FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(selector: &str) -> Result<Self, Self::Err> {
+        Selector::parse(selector)
+    }
+}
+
+impl Compound {
+    fn matches(&self, component: &Component) -> bool {
+        // Text nodes and comments are named "#text" and "#comment"
+        if component.elem.starts_with('#') {
+            return false;
+        }
+        if self
+            .elem
+            .as_ref()
+            .is_some_and(|elem| *elem != component.elem)
+        {
+            return false;
+        }
+        let classes = component.attribute("class").unwrap_or_default();
+        self.classes
+            .iter()
+            .all(|class_name| classes.split_whitespace().any(|c| c == class_name))
+            && self.attributes.iter().all(|(name, value)| {
+                match (component.attribute(name), value) {
+                    (Some(actual), Some(value)) => actual == value,
+                    (actual, None) => actual.is_some(),
+                    (None, Some(_)) => false,
+                }
+            })
+    }
+}
+
+impl Component {
+    // First element matching a CSS selector, see Selector
+    pub fn query(&self, selector: &str) -> Result<Option<&Component>, SelectorError> {
+        let paths = Selector::parse(selector)?.paths(self);
+        Ok(paths.first().and_then(|path| self.at_path(path)))
+    }
+
+    // All elements matching a CSS selector, in document order
+    pub fn query_all(&self, selector: &str) -> Result<Vec<&Component>, SelectorError> {
+        Ok(Selector::parse(selector)?
+            .paths(self)
+            .iter()
+            .filter_map(|path| self.at_path(path))
+            .collect())
+    }
+
+    // Paths of all elements matching a CSS selector, to modify them with at_path_mut
+    pub fn query_paths(&self, selector: &str) -> Result<Vec<Vec<usize>>, SelectorError> {
+        Ok(Selector::parse(selector)?.paths(self))
+    }
+
+    pub fn at_path(&self, path: &[usize]) -> Option<&Component> {
+        path.iter()
+            .try_fold(self, |component, &i| component.children.get(i))
+    }
+
+    pub fn at_path_mut(&mut self, path: &[usize]) -> Option<&mut Component> {
+        path.iter()
+            .try_fold(self, |component, &i| component.children.get_mut(i))
+    }
+}
+
+struct SelectorParser<'a> {
+    selector: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl SelectorParser<'_> {
+    fn parse(mut self) -> Result<Selector, SelectorError> {
+        let mut compounds = Vec::new();
+        let mut combinators = Vec::new();
+        self.skip_whitespace();
+        loop {
+            compounds.push(self.compound()?);
+            let whitespace = self.skip_whitespace();
+            let combinator = match self.chars.peek() {
+                None => break,
+                Some((_, '>')) => {
+                    self.chars.next();
+                    self.skip_whitespace();
+                    Combinator::Child
+                }
+                Some(_) if whitespace => Combinator::Descendant,
+                Some(&(position, c)) => {
+                    return Err(self.error(position, format!("Unexpected {:?}", c)));
+                }
+            };
+            combinators.push(combinator);
+        }
+        Ok(Selector {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let start = self.position();
+        let mut compound = Compound::default();
+        let mut empty = true;
+        match self.chars.peek() {
+            Some((_, '*')) => {
+                self.chars.next();
+                empty = false;
+            }
+            Some(&(_, c)) if is_name_char(c) => {
+                compound.elem = Some(self.name()?);
+                empty = false;
+            }
+            _ => {}
+        }
+        loop {
+            match self.chars.peek().map(|&(_, c)| c) {
+                Some('#') => {
+                    self.chars.next();
+                    compound
+                        .attributes
+                        .push(("id".to_string(), Some(self.name()?)));
+                }
+                Some('.') => {
+                    self.chars.next();
+                    compound.classes.push(self.name()?);
+                }
+                Some('[') => {
+                    self.chars.next();
+                    compound.attributes.push(self.attribute()?);
+                }
+                _ => break,
+            }
+            empty = false;
+        }
+        if empty {
+            return Err(self.error(start, "Expected a tag, id, class or attribute".to_string()));
+        }
+        Ok(compound)
+    }
+
+    // [name] or [name=value], after the opening bracket
+    fn attribute(&mut self) -> Result<(String, Option<String>), SelectorError> {
+        self.skip_whitespace();
+        let name = self.name()?;
+        self.skip_whitespace();
+        let value = match self.chars.next() {
+            Some((_, ']')) => return Ok((name, None)),
+            Some((_, '=')) => {
+                self.skip_whitespace();
+                match self.chars.peek().map(|&(_, c)| c) {
+                    Some(quote @ ('"' | '\'')) => {
+                        self.chars.next();
+                        self.quoted(quote)?
+                    }
+                    _ => self.name()?,
+                }
+            }
+            Some((position, c)) => {
+                return Err(self.error(position, format!("Unexpected {:?}", c)));
+            }
+            None => return Err(self.error(self.selector.len(), "Missing ]".to_string())),
+        };
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some((_, ']')) => Ok((name, Some(value))),
+            Some((position, c)) => Err(self.error(position, format!("Unexpected {:?}", c))),
+            None => Err(self.error(self.selector.len(), "Missing ]".to_string())),
+        }
+    }
+
+    // Tag, id, class or attribute name, with backslash escapes
+    fn name(&mut self) -> Result<String, SelectorError> {
+        let start = self.position();
+        let mut name = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c == '\\' {
+                self.chars.next();
+                match self.chars.next() {
+                    Some((_, escaped)) => name.push(escaped),
+                    None => return Err(self.error(start, "Escape at the end".to_string())),
+                }
+            } else if is_name_char(c) {
+                self.chars.next();
+                name.push(c);
+            } else {
+                break;
+            }
+        }
+        if name.is_empty() {
+            return Err(self.error(start, "Expected a name".to_string()));
+        }
+        Ok(name)
+    }
+
+    // String value after its opening quote
+    fn quoted(&mut self, quote: char) -> Result<String, SelectorError> {
+        let start = self.position();
+        let mut value = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, c)) if c == quote => return Ok(value),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => break,
+                },
+                Some((_, c)) => value.push(c),
+                None => break,
+            }
+        }
+        Err(self.error(start, "Unterminated string".to_string()))
+    }
+
+    // Returns whether there was any whitespace
+    fn skip_whitespace(&mut self) -> bool {
+        let mut skipped = false;
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {
+            skipped = true;
+        }
+        skipped
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.selector.len(), |&(i, _)| i)
+    }
+
+    fn error(&self, position: usize, message: String) -> SelectorError {
+        SelectorError { message, position }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
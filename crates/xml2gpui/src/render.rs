@@ -0,0 +1,568 @@
+use gpui::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use xml2gpui_macros::tailwind_to_gpui;
+
+use crate::classes::with_tailwind_classes;
+use crate::diagnostics::{report, Diagnostic};
+use crate::form::{render_control, Controls, FormState};
+use crate::grid::{is_grid, render_grid};
+use crate::list::{render_virtual_list, ListSource};
+use crate::style::{self, Corner, Declaration, LineHeight, ResolvedStyle, Side};
+use crate::text::render_inline;
+use crate::theme::Theme;
+use crate::tree::{
+    element_key, group_children, scroll_axes, ChildGroup, Component, ElementKeys, BLOCK_ELEMENTS,
+    CONTROL_ELEMENTS,
+};
+
+// State the host view keeps between renders. Entries are keyed by the element's id attribute,
+// or by its path in the tree when it has none, so they survive hot reloads as long as the
+// corresponding node still exists.
+#[derive(Default)]
+pub struct RenderState {
+    pub theme: Theme,
+    // Values and handlers form controls are bound to
+    pub form: Option<Model<FormState>>,
+    list_sources: HashMap<String, Rc<dyn ListSource>>,
+    // Values of unbound controls, input cursors and the open select
+    pub(crate) controls: Rc<RefCell<Controls>>,
+    focus_handles: HashMap<SharedString, FocusHandle>,
+    list_scroll_handles: HashMap<SharedString, UniformListScrollHandle>,
+    scroll_handles: HashMap<SharedString, ScrollHandle>,
+    // Last scroll-to attribute of each scrollable element, so it only scrolls when it changes
+    scroll_targets: HashMap<SharedString, String>,
+    // Changed scroll-to targets of the scrollable elements being rendered, innermost last.
+    // Scrollable elements inside them scroll towards the target too.
+    scroll_requests: Vec<String>,
+    // Id of an element to scroll into view, taken by every scrollable element containing it
+    // until the render ends
+    pending_scroll: Option<String>,
+    pending_scroll_found: bool,
+}
+
+impl RenderState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn focus_handle(&mut self, key: &SharedString, cx: &mut WindowContext) -> FocusHandle {
+        self.focus_handles
+            .entry(key.clone())
+            .or_insert_with(|| cx.focus_handle())
+            .clone()
+    }
+
+    pub fn set_list_source(&mut self, name: impl Into<String>, source: Rc<dyn ListSource>) {
+        self.list_sources.insert(name.into(), source);
+    }
+
+    pub fn list_source(&self, name: &str) -> Option<Rc<dyn ListSource>> {
+        self.list_sources.get(name).cloned()
+    }
+
+    pub fn list_scroll_handle(&mut self, key: &SharedString) -> UniformListScrollHandle {
+        self.list_scroll_handles
+            .entry(key.clone())
+            .or_default()
+            .clone()
+    }
+
+    // Scrolls the scrollable ancestors of the element with this id on the next render
+    pub fn scroll_to(&mut self, id: impl Into<String>) {
+        self.pending_scroll = Some(id.into());
+        self.pending_scroll_found = false;
+    }
+
+    // Called before rendering the children of a scrollable element. Returns the target of its
+    // scroll-to attribute if that changed, which stays requested until scroll_handle.
+    fn begin_scroll(&mut self, key: &SharedString, component: &Component) -> Option<String> {
+        let target = component.attribute("scroll-to")?;
+        if self.scroll_targets.get(key).map(String::as_str) == Some(target) {
+            return None;
+        }
+        self.scroll_targets.insert(key.clone(), target.to_string());
+        self.scroll_requests.push(target.to_string());
+        Some(target.to_string())
+    }
+
+    // Scroll handle of a scrollable element, called after rendering its children. Scrolls to
+    // the child containing the innermost requested target, or the pending scroll_to. A target
+    // of the element's own scroll-to attribute ends here and is reported if it isn't found.
+    fn scroll_handle(
+        &mut self,
+        key: &SharedString,
+        component: &Component,
+        own_target: Option<String>,
+    ) -> ScrollHandle {
+        let handle = self.scroll_handles.entry(key.clone()).or_default().clone();
+
+        let child_containing = |target: &str| {
+            component
+                .children
+                .iter()
+                .position(|child| contains_id(child, target))
+        };
+        let requested = self
+            .scroll_requests
+            .iter()
+            .rev()
+            .find_map(|target| child_containing(target));
+        let pending = self.pending_scroll.as_deref().and_then(child_containing);
+        if pending.is_some() {
+            self.pending_scroll_found = true;
+        }
+        if let Some(index) = requested.or(pending) {
+            handle.scroll_to_item(index);
+        }
+
+        if let Some(target) = own_target {
+            self.scroll_requests.pop();
+            if child_containing(&target).is_none() {
+                report(Diagnostic::Element(format!(
+                    "scroll-to target {} not found in {}",
+                    target, key
+                )));
+            }
+        }
+        handle
+    }
+
+    // Called at the end of a render, a scroll_to lasts for one render
+    fn end_scroll(&mut self) {
+        if let Some(target) = self.pending_scroll.take() {
+            if !self.pending_scroll_found {
+                report(Diagnostic::Element(format!(
+                    "No scrollable element contains {}, see XmlView::scroll_to",
+                    target
+                )));
+            }
+        }
+    }
+
+    // Drops state belonging to nodes that no longer exist in the tree
+    pub fn retain(&mut self, root: &Component) {
+        let keys = ElementKeys::new(root);
+        self.focus_handles.retain(|key, _| keys.contains(key));
+        self.list_scroll_handles.retain(|key, _| keys.contains(key));
+        self.scroll_handles.retain(|key, _| keys.contains(key));
+        self.scroll_targets.retain(|key, _| keys.contains(key));
+        self.controls.borrow_mut().retain(|key| keys.contains(key));
+    }
+}
+
+// Name GPUI records the bounds of a rendered element under, for the snapshot harness. It is
+// only recorded with GPUI's test-support feature.
+pub(crate) fn debug_selector(path: &[usize]) -> String {
+    let path = path.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+    format!("xml2gpui:/{}", path.join("/"))
+}
+
+fn contains_id(component: &Component, id: &str) -> bool {
+    component.attribute("id") == Some(id)
+        || component
+            .children
+            .iter()
+            .any(|child| contains_id(child, id))
+}
+
+// I can't use dynamic trait objects, because Styled and IntoElement are not object-safe (have : Sized supertrait)
+// https://doc.rust-lang.org/reference/items/traits.html#object-safety
+// Sized must not be a supertrait. In other words, it must not require Self: Sized.
+pub enum ComponentType {
+    Div(Div),
+    Stateful(Stateful<Div>),
+    Img(Img),
+    Svg(Svg),
+    // Elements whose concrete type doesn't matter to the caller once built
+    Any(AnyElement),
+}
+
+impl ComponentType {
+    pub fn into_any_element(self) -> AnyElement {
+        match self {
+            ComponentType::Div(div) => div.into_any_element(),
+            ComponentType::Stateful(div) => div.into_any_element(),
+            ComponentType::Img(img) => img.into_any_element(),
+            ComponentType::Svg(svg) => svg.into_any_element(),
+            ComponentType::Any(element) => element,
+        }
+    }
+}
+
+// View that renders markup with a RenderState, like XmlView
+pub trait RenderView: Render {
+    fn render_state(&mut self) -> &mut RenderState;
+}
+
+// Rows of a virtual list: the elements for a range of row indices
+pub(crate) type RowRenderer =
+    dyn Fn(&mut RenderState, Range<usize>, &mut WindowContext) -> Vec<AnyElement>;
+
+// Handle to the view being rendered, for elements like <virtual-list> that render their
+// rows later through it. Only render_component knows the type of the view.
+#[derive(Clone)]
+pub(crate) struct ViewHandle {
+    uniform_list: Rc<dyn Fn(SharedString, usize, Rc<RowRenderer>) -> UniformList>,
+}
+
+impl ViewHandle {
+    fn new<V: RenderView>(view: View<V>) -> Self {
+        ViewHandle {
+            uniform_list: Rc::new(move |id, row_count, rows| {
+                uniform_list(view.clone(), id, row_count, move |this, range, cx| {
+                    rows(this.render_state(), range, cx)
+                })
+            }),
+        }
+    }
+
+    pub(crate) fn uniform_list(
+        &self,
+        id: SharedString,
+        row_count: usize,
+        rows: impl Fn(&mut RenderState, Range<usize>, &mut WindowContext) -> Vec<AnyElement> + 'static,
+    ) -> UniformList {
+        (self.uniform_list)(id, row_count, Rc::new(rows))
+    }
+}
+
+// Renders a tree in the render method of a view. `state` is usually a field of the view,
+// the one RenderView::render_state returns.
+pub fn render_component<V: RenderView>(
+    component: &Component,
+    state: &mut RenderState,
+    cx: &mut ViewContext<V>,
+) -> ComponentType {
+    let text_style = cx.text_style();
+    let view = ViewHandle::new(cx.view().clone());
+    let element = render_node(component, &mut Vec::new(), &text_style, state, &view, cx);
+    state.end_scroll();
+    element
+}
+
+pub(crate) fn render_node(
+    component: &Component,
+    path: &mut Vec<usize>,
+    // Text style inherited from the ancestors, used to build paragraphs of inline elements
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> ComponentType {
+    let element = match component.elem.as_str() {
+        elem if BLOCK_ELEMENTS.contains(&elem) || elem == "br" => {
+            // Elements with an identity get GPUI element state (hover, active, scroll offset),
+            // which is keyed by the id and therefore preserved across reloads
+            let key = SharedString::from(element_key(component, path));
+            let scroll = scroll_axes(component);
+            let scroll_target = match scroll {
+                Some(_) => state.begin_scroll(&key, component),
+                None => None,
+            };
+
+            let element = render_block(component, path, text_style, state, view, cx)
+                .debug_selector(|| debug_selector(path));
+
+            let focusable = component.attribute("tabindex").is_some();
+            if !focusable && component.attribute("id").is_none() && scroll.is_none() {
+                ComponentType::Div(element)
+            } else {
+                let mut element = element.id(key.clone());
+                if let Some((x, y)) = scroll {
+                    element = match (x, y) {
+                        (true, true) => element.overflow_scroll(),
+                        (true, false) => element.overflow_x_scroll(),
+                        _ => element.overflow_y_scroll(),
+                    };
+                    let handle = state.scroll_handle(&key, component, scroll_target);
+                    element = element.track_scroll(&handle);
+                }
+                if focusable {
+                    // Focusable elements keep their focus handle across renders and reloads
+                    let focus_handle = state.focus_handle(&key, cx);
+                    ComponentType::Any(element.track_focus(&focus_handle).into_any_element())
+                } else {
+                    ComponentType::Stateful(element)
+                }
+            }
+        }
+        elem if CONTROL_ELEMENTS.contains(&elem) => {
+            render_control(component, path, text_style, state, view, cx)
+        }
+        "virtual-list" => render_virtual_list(component, path, text_style, state, view, cx),
+        _ if component.is_inline() => {
+            let key = element_key(component, path);
+            let nodes = std::slice::from_ref(component);
+            match render_inline(nodes, key.into(), text_style, &state.theme) {
+                Some(paragraph) => ComponentType::Any(paragraph),
+                None => ComponentType::Div(div()),
+            }
+        }
+        "img" => {
+            // Get attribute "src"
+            if let Some(src) = component.attribute("src") {
+                let mut element = img(src.to_string()).debug_selector(|| debug_selector(path));
+                element = set_attributes::<Img>(element, &component.attributes);
+                ComponentType::Img(element)
+            } else {
+                ComponentType::Div(div().child("Error: img element must have src attribute"))
+            }
+        }
+        "svg" => {
+            // Get attribute "path"
+            if let Some(svg_path) = component.attribute("path") {
+                let mut element = svg()
+                    .path(svg_path.to_string())
+                    .debug_selector(|| debug_selector(path));
+                element = set_attributes::<Svg>(element, &component.attributes);
+                ComponentType::Svg(element)
+            } else {
+                ComponentType::Div(div().child("Error: svg element must have path attribute"))
+            }
+        }
+        _ => ComponentType::Div(div()),
+    };
+
+    element
+}
+
+// Div with the theme and element classes applied and the children rendered into it
+pub(crate) fn render_block(
+    component: &Component,
+    path: &mut Vec<usize>,
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> Div {
+    let (element, text_style) = styled_block(component, text_style, state);
+    if is_grid(component) {
+        render_grid(element, component, path, &text_style, state, view, cx)
+    } else {
+        render_children(element, component, path, &text_style, state, view, cx)
+    }
+}
+
+// Div with the theme and element classes applied, and the text style its children inherit
+pub(crate) fn styled_block(
+    component: &Component,
+    text_style: &TextStyle,
+    state: &RenderState,
+) -> (Div, TextStyle) {
+    // Theme classes go first so that the element's own classes override them
+    let mut element =
+        ResolvedStyle::resolve(&component.elem, &component.attributes, &state.theme).apply(div());
+
+    let mut text_style = text_style.clone();
+    if let Some(refinement) = element.text_style().clone() {
+        text_style.refine(&refinement);
+    }
+    (element, text_style)
+}
+
+pub(crate) fn render_children(
+    mut element: Div,
+    component: &Component,
+    path: &mut Vec<usize>,
+    text_style: &TextStyle,
+    state: &mut RenderState,
+    view: &ViewHandle,
+    cx: &mut WindowContext,
+) -> Div {
+    for group in group_children(component) {
+        match group {
+            ChildGroup::Inline(nodes) => {
+                let (first, first_node) = nodes[0];
+                path.push(first);
+                let id = element_key(first_node, path);
+                path.pop();
+                let paragraph = render_inline(
+                    nodes.iter().map(|(_, node)| *node),
+                    id.into(),
+                    text_style,
+                    &state.theme,
+                );
+                if let Some(paragraph) = paragraph {
+                    element = element.child(paragraph);
+                    continue;
+                }
+                // Line breaks without any text around them are empty lines
+                for (j, node) in nodes.into_iter().filter(|(_, node)| node.elem == "br") {
+                    path.push(j);
+                    let child = render_node(node, path, text_style, state, view, cx);
+                    element = element.child(child.into_any_element());
+                    path.pop();
+                }
+            }
+            ChildGroup::Block(i, node, marker) => {
+                path.push(i);
+                let mut child =
+                    render_node(node, path, text_style, state, view, cx).into_any_element();
+                path.pop();
+
+                // List items get a bullet or number in front of them
+                if let Some(marker) = marker {
+                    child = div()
+                        .flex()
+                        .flex_row()
+                        .child(div().flex_none().w_6().child(marker))
+                        .child(child)
+                        .into_any_element();
+                }
+                element = element.child(child);
+            }
+        }
+    }
+
+    // Add text if exists
+    if let Some(text) = &component.text {
+        element = element.child(text.clone());
+    }
+
+    element
+}
+
+pub(crate) fn set_attributes<T: Styled>(element: T, attributes: &[(String, String)]) -> T {
+    ResolvedStyle::from_attributes(attributes).apply(element)
+}
+
+impl ResolvedStyle {
+    pub fn apply<T: Styled>(&self, mut element: T) -> T {
+        if let Some(font) = &self.font {
+            element = element.font(SharedString::from(font.clone()));
+        }
+        for declaration in &self.declarations {
+            element = apply_declaration(element, declaration);
+        }
+        if let Some(color) = self.shadow_color {
+            if let Some(shadows) = &mut element.style().box_shadow {
+                for shadow in shadows.iter_mut() {
+                    shadow.color = rgba(color.0).into();
+                }
+            }
+        }
+        element
+    }
+}
+
+fn apply_declaration<T: Styled>(mut element: T, declaration: &Declaration) -> T {
+    match declaration {
+        Declaration::Class(class_name) => {
+            let class_name = class_name.as_str();
+            // Macro magick to convert tailwind classes to gpui. Creates "match class_name { "class-name" => element.class_name() }"
+            // with the classes of classes.rs
+            with_tailwind_classes!(tailwind_to_gpui!(element, class_name,)
+                // Only classes of the table are resolved to Declaration::Class
+                _ => element
+            )
+        }
+        Declaration::Background(color) => element.bg(rgba(color.0)),
+        Declaration::TextColor(color) => element.text_color(rgba(color.0)),
+        Declaration::BorderColor(color) => element.border_color(rgba(color.0)),
+        Declaration::Rounded(corner, length) => {
+            let length = absolute_length(*length);
+            match corner {
+                Corner::All => element.rounded(length),
+                Corner::Top => element.rounded_t(length),
+                Corner::Right => element.rounded_r(length),
+                Corner::Bottom => element.rounded_b(length),
+                Corner::Left => element.rounded_l(length),
+                Corner::TopLeft => element.rounded_tl(length),
+                Corner::TopRight => element.rounded_tr(length),
+                Corner::BottomRight => element.rounded_br(length),
+                Corner::BottomLeft => element.rounded_bl(length),
+            }
+        }
+        Declaration::BorderWidth(side, length) => {
+            let length = absolute_length(*length);
+            match side {
+                Side::Top => element.border_t_width(length),
+                Side::Right => element.border_r_width(length),
+                Side::Bottom => element.border_b_width(length),
+                Side::Left => element.border_l_width(length),
+            }
+        }
+        Declaration::Top(length) => element.top(absolute_length(*length)),
+        Declaration::Left(length) => element.left(absolute_length(*length)),
+        Declaration::Width(length) => element.w(absolute_length(*length)),
+        Declaration::Height(length) => element.h(absolute_length(*length)),
+        Declaration::Opacity(opacity) => {
+            element.style().opacity = Some(*opacity);
+            element
+        }
+        Declaration::Hidden => {
+            element.style().display = Some(Display::None);
+            element
+        }
+        Declaration::Shadows(shadows) => {
+            element.style().box_shadow = Some(
+                shadows
+                    .iter()
+                    .map(|shadow| BoxShadow {
+                        color: rgba(shadow.color.0).into(),
+                        offset: point(px(shadow.x), px(shadow.y)),
+                        blur_radius: px(shadow.blur),
+                        spread_radius: px(shadow.spread),
+                    })
+                    .collect(),
+            );
+            element
+        }
+        Declaration::FontSize(size) => {
+            text_style(&mut element).font_size = Some(rems(*size).into());
+            element
+        }
+        Declaration::LineHeight(LineHeight::Relative(height)) => {
+            text_style(&mut element).line_height = Some(relative(*height));
+            element
+        }
+        Declaration::LineHeight(LineHeight::Rems(height)) => {
+            text_style(&mut element).line_height = Some(rems(*height).into());
+            element
+        }
+        Declaration::Italic(italic) => {
+            text_style(&mut element).font_style = Some(if *italic {
+                FontStyle::Italic
+            } else {
+                FontStyle::Normal
+            });
+            element
+        }
+        Declaration::Underline(underline) => {
+            text_style(&mut element).underline = underline.then(|| UnderlineStyle {
+                thickness: px(1.),
+                ..Default::default()
+            });
+            element
+        }
+        Declaration::Nowrap(nowrap) => {
+            text_style(&mut element).white_space = Some(if *nowrap {
+                WhiteSpace::Nowrap
+            } else {
+                WhiteSpace::Normal
+            });
+            element
+        }
+        Declaration::Truncate => {
+            text_style(&mut element).white_space = Some(WhiteSpace::Nowrap);
+            element.style().overflow.x = Some(Overflow::Hidden);
+            element
+        }
+    }
+}
+
+fn absolute_length(length: style::Length) -> AbsoluteLength {
+    match length {
+        style::Length::Pixels(value) => AbsoluteLength::Pixels(px(value)),
+        style::Length::Rems(value) => AbsoluteLength::Rems(rems(value)),
+    }
+}
+
+fn text_style<T: Styled>(element: &mut T) -> &mut TextStyleRefinement {
+    element.text_style().get_or_insert_with(Default::default)
+}
@@ -0,0 +1,125 @@
+use crate::diagnostics::{report, Diagnostic};
+use crate::style::{Color, Declaration, ResolvedStyle, Shadow};
+
+// Color of arbitrary shadows that don't name one, the same as Tailwind's default
+const DEFAULT_SHADOW_COLOR: Color = Color(0x0000001a);
+const REM_IN_PIXELS: f32 = 16.;
+
+// Resolves shadow-none and arbitrary shadows like shadow-[0_4px_12px_#00000040], where
+// underscores separate x, y, blur, spread and color, and commas separate shadows. Returns
+// false if the class isn't one of them.
+pub(crate) fn resolve_shadow_class(style: &mut ResolvedStyle, class_name: &str) -> bool {
+    if class_name == "shadow-none" {
+        style.push(Declaration::Shadows(Vec::new()));
+        return true;
+    }
+    if class_name == "shadow-inner" {
+        report(Diagnostic::Unsupported(
+            "shadow-inner is not supported: the pinned GPUI revision has no inset shadows"
+                .to_string(),
+        ));
+        return true;
+    }
+    // Colors apply after all classes, see shadow_color
+    if let Some(value) = class_name.strip_prefix("shadow-color-[") {
+        let color = value
+            .strip_prefix('#')
+            .and_then(|value| value.strip_suffix(']'))
+            .and_then(Color::from_hex);
+        if color.is_none() {
+            report(Diagnostic::InvalidValue(class_name.to_string()));
+        }
+        return true;
+    }
+    let Some(value) = class_name
+        .strip_prefix("shadow-[")
+        .and_then(|value| value.strip_suffix(']'))
+    else {
+        return false;
+    };
+
+    let shadows: Option<Vec<Shadow>> = value.split(',').map(parse_shadow).collect();
+    match shadows {
+        Some(shadows) => style.push(Declaration::Shadows(shadows)),
+        None => report(Diagnostic::InvalidValue(class_name.to_string())),
+    }
+    true
+}
+
+// Color of the shadow-color-[#RRGGBBAA] class of an element, which recolors its shadows
+// whichever class they came from
+pub(crate) fn shadow_color(classes: &str) -> Option<Color> {
+    classes.split_whitespace().rev().find_map(|class_name| {
+        class_name
+            .strip_prefix("shadow-color-[#")?
+            .strip_suffix(']')
+            .and_then(Color::from_hex)
+    })
+}
+
+// The pinned GPUI revision only paints solid backgrounds. Gradients fall back to their from
+// color, with a diagnostic.
+pub(crate) fn resolve_gradient_class(style: &mut ResolvedStyle, class_name: &str) -> bool {
+    if class_name.starts_with("bg-gradient-to-") {
+        report(Diagnostic::Unsupported(format!(
+            "{} is not supported: the pinned GPUI revision has no gradients, using the from color",
+            class_name
+        )));
+        return true;
+    }
+    // Only arbitrary colors, named ones like from-blue-500 are left unrecognized
+    let Some((stop, hex)) = ["from-[#", "via-[#", "to-[#"]
+        .into_iter()
+        .find_map(|prefix| {
+            let hex = class_name.strip_prefix(prefix)?.strip_suffix(']')?;
+            Some((prefix, hex))
+        })
+    else {
+        return false;
+    };
+    match Color::from_hex(hex) {
+        // The via and to colors have nothing to apply to without gradients
+        Some(color) if stop == "from-[#" => style.push(Declaration::Background(color)),
+        Some(_) => {}
+        None => report(Diagnostic::InvalidValue(class_name.to_string())),
+    }
+    true
+}
+
+fn parse_shadow(value: &str) -> Option<Shadow> {
+    let mut lengths = Vec::new();
+    let mut color = None;
+    for part in value.split('_').filter(|part| !part.is_empty()) {
+        if let Some(hex) = part.strip_prefix('#') {
+            color = Some(Color::from_hex(hex)?);
+        } else if part == "inset" {
+            report(Diagnostic::Unsupported(
+                "Inset shadows are not supported by the pinned GPUI revision".to_string(),
+            ));
+        } else {
+            lengths.push(parse_pixels(part)?);
+        }
+    }
+    if lengths.len() < 2 || lengths.len() > 4 {
+        return None;
+    }
+
+    Some(Shadow {
+        color: color.unwrap_or(DEFAULT_SHADOW_COLOR),
+        x: lengths[0],
+        y: lengths[1],
+        blur: lengths.get(2).copied().unwrap_or(0.),
+        spread: lengths.get(3).copied().unwrap_or(0.),
+    })
+}
+
+fn parse_pixels(value: &str) -> Option<f32> {
+    if let Some(rems) = value.strip_suffix("rem") {
+        return rems.parse::<f32>().ok().map(|rems| rems * REM_IN_PIXELS);
+    }
+    value
+        .strip_suffix("px")
+        .unwrap_or(value)
+        .parse::<f32>()
+        .ok()
+}
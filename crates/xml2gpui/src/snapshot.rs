@@ -0,0 +1,214 @@
+use gpui::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::diagnostics::collect_diagnostics;
+use crate::render::{debug_selector, render_component, RenderState, RenderView};
+use crate::style::ResolvedStyle;
+use crate::template::resolve_templates;
+use crate::theme::Theme;
+use crate::tree::{try_parse_xml, Component};
+
+thread_local! {
+    static SELECTORS: RefCell<HashMap<String, &'static str>> = RefCell::new(HashMap::new());
+}
+
+// Set to 1 to write every snapshot compared with assert_snapshot instead of comparing it
+const UPDATE_VARIABLE: &str = "XML2GPUI_UPDATE_SNAPSHOTS";
+
+// Renders a tree like XmlView does, without files to load
+struct SnapshotView {
+    root: Component,
+    state: RenderState,
+}
+
+impl RenderView for SnapshotView {
+    fn render_state(&mut self) -> &mut RenderState {
+        &mut self.state
+    }
+}
+
+impl Render for SnapshotView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        render_component(&self.root, &mut self.state, cx).into_any_element()
+    }
+}
+
+// Renders markup in a window of GPUI's test platform and describes the result, one line per
+// laid out element: its name and id, bounds (x,y wxh in pixels) and the styles its classes
+// set. Text in paragraphs is part of the bounds of the enclosing element. Templates are
+// expanded and the default theme applied, like XmlView does. Panics if the markup doesn't
+// parse.
+pub fn snapshot(cx: &mut TestAppContext, xml: &str) -> String {
+    let root = resolve_templates(try_parse_xml(xml).unwrap_or_else(|e| panic!("{}", e)));
+    let theme = Theme::default();
+    let (_, cx) = cx.add_window_view(|_| SnapshotView {
+        root: root.clone(),
+        state: RenderState::new(),
+    });
+    // Test windows are drawn as soon as the effects are flushed
+    cx.run_until_parked();
+    let rem_size = cx.update(|cx| cx.rem_size());
+
+    let mut lines = Vec::new();
+    collect_lines(&root, &mut Vec::new(), 0, &theme, rem_size, cx, &mut lines);
+    lines.join("\n") + "\n"
+}
+
+fn collect_lines(
+    component: &Component,
+    path: &mut Vec<usize>,
+    depth: usize,
+    theme: &Theme,
+    rem_size: Pixels,
+    cx: &mut VisualTestContext,
+    lines: &mut Vec<String>,
+) {
+    let bounds = cx.debug_bounds(static_selector(debug_selector(path)));
+
+    let mut depth = depth;
+    if let Some(bounds) = bounds {
+        let mut line = "  ".repeat(depth);
+        line.push_str(&component.elem);
+        if let Some(id) = component.attribute("id") {
+            write!(line, "#{}", id).unwrap();
+        }
+        write!(
+            line,
+            " {},{} {}x{}",
+            f32::from(bounds.origin.x),
+            f32::from(bounds.origin.y),
+            f32::from(bounds.size.width),
+            f32::from(bounds.size.height)
+        )
+        .unwrap();
+        for style in key_styles(component, theme, rem_size) {
+            line.push(' ');
+            line.push_str(&style);
+        }
+        lines.push(line);
+        depth += 1;
+    }
+
+    for (i, child) in component.children.iter().enumerate() {
+        path.push(i);
+        collect_lines(child, path, depth, theme, rem_size, cx, lines);
+        path.pop();
+    }
+}
+
+// debug_bounds only takes static names. Each selector is leaked the first time it is looked
+// up and reused after that, so the memory is bounded by the paths of the largest tree.
+fn static_selector(selector: String) -> &'static str {
+    SELECTORS.with(|selectors| {
+        *selectors
+            .borrow_mut()
+            .entry(selector)
+            .or_insert_with_key(|selector| Box::leak(selector.clone().into_boxed_str()))
+    })
+}
+
+// Styles that most often explain a layout change, as name=value
+fn key_styles(component: &Component, theme: &Theme, rem_size: Pixels) -> Vec<String> {
+    // The renderer reported the problems already
+    let (resolved, _) = collect_diagnostics(|| {
+        ResolvedStyle::resolve(&component.elem, &component.attributes, theme)
+    });
+    let mut element = resolved.apply(div());
+    let style = element.style();
+
+    let mut styles = Vec::new();
+    if let Some(display) = style.display {
+        styles.push(format!("display={:?}", display).to_lowercase());
+    }
+    if let Some(direction) = style.flex_direction {
+        styles.push(format!("direction={:?}", direction).to_lowercase());
+    }
+    if let Some(Fill::Color(color)) = style.background {
+        styles.push(format!("background={}", hex(color)));
+    }
+    if let Some(text) = &style.text {
+        if let Some(color) = text.color {
+            styles.push(format!("color={}", hex(color)));
+        }
+        if let Some(size) = text.font_size {
+            styles.push(format!("font-size={}", f32::from(size.to_pixels(rem_size))));
+        }
+        if let Some(weight) = text.font_weight {
+            styles.push(format!("font-weight={}", weight.0));
+        }
+    }
+    if let Some(opacity) = style.opacity {
+        styles.push(format!("opacity={}", opacity));
+    }
+    styles
+}
+
+fn hex(color: Hsla) -> String {
+    let color = Rgba::from(color);
+    let channel = |value: f32| (value * 255.).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        channel(color.a)
+    )
+}
+
+// Compares a snapshot with the one checked in at `path`. A missing file is written and fails
+// the test once, so that new snapshots get reviewed. With XML2GPUI_UPDATE_SNAPSHOTS=1 the
+// files are written instead of compared.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let update = std::env::var(UPDATE_VARIABLE).is_ok_and(|value| value == "1");
+    let expected = std::fs::read_to_string(path).ok();
+    if !update && expected.as_deref() == Some(actual) {
+        return;
+    }
+    if update || expected.is_none() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        std::fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("Could not write {}: {}", path.display(), e));
+        if update {
+            return;
+        }
+        panic!(
+            "New snapshot written to {}, review it and run again",
+            path.display()
+        );
+    }
+
+    panic!(
+        "Snapshot {} doesn't match, run with {}=1 to update it:\n{}",
+        path.display(),
+        UPDATE_VARIABLE,
+        line_diff(expected.as_deref().unwrap_or_default(), actual)
+    );
+}
+
+// Changed lines with their line numbers, "-" from the expected and "+" from the actual
+// snapshot
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(i), actual.get(i));
+        if old == new {
+            continue;
+        }
+        if let Some(old) = old {
+            writeln!(diff, "{:4} - {}", i + 1, old).unwrap();
+        }
+        if let Some(new) = new {
+            writeln!(diff, "{:4} + {}", i + 1, new).unwrap();
+        }
+    }
+    diff
+}
@@ -0,0 +1,293 @@
+use crate::classes::tailwind_method;
+use crate::diagnostics::{report, Diagnostic};
+use crate::grid::is_grid_class;
+use crate::shadow::{resolve_gradient_class, resolve_shadow_class, shadow_color};
+use crate::theme::Theme;
+use crate::tree::SCROLL_CLASSES;
+use crate::typography::resolve_typography_class;
+
+// What the font and class attributes of an element resolve to, without GPUI types, so that
+// class handling can be inspected and tested without a window. ResolvedStyle::apply (see
+// render.rs) applies it to a GPUI element. Problems are reported while resolving, see
+// diagnostics.rs.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolvedStyle {
+    pub font: Option<String>,
+    // In class order, as a later class overrides what an earlier one set
+    pub declarations: Vec<Declaration>,
+    // Recolors every shadow, whichever class it came from
+    pub shadow_color: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Declaration {
+    // Class of the Tailwind table in classes.rs, applied with its Styled method
+    Class(String),
+    Background(Color),
+    TextColor(Color),
+    BorderColor(Color),
+    Rounded(Corner, Length),
+    BorderWidth(Side, Length),
+    Top(Length),
+    Left(Length),
+    Width(Length),
+    Height(Length),
+    // 0 to 1
+    Opacity(f32),
+    // display: none
+    Hidden,
+    // No shadows for shadow-none
+    Shadows(Vec<Shadow>),
+    // In rems
+    FontSize(f32),
+    LineHeight(LineHeight),
+    Italic(bool),
+    Underline(bool),
+    Nowrap(bool),
+    // No wrapping, and hidden horizontal overflow
+    Truncate,
+}
+
+// 0xRRGGBBAA, like gpui::rgba takes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Length {
+    Pixels(f32),
+    Rems(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineHeight {
+    // Multiple of the font size
+    Relative(f32),
+    Rems(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corner {
+    All,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+// Lengths in pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shadow {
+    pub color: Color,
+    pub x: f32,
+    pub y: f32,
+    pub blur: f32,
+    pub spread: f32,
+}
+
+impl ResolvedStyle {
+    // Style of an element with the theme applied, the way render_block styles it
+    pub fn resolve(elem: &str, attributes: &[(String, String)], theme: &Theme) -> Self {
+        Self::from_attributes(&theme.apply(elem, attributes))
+    }
+
+    pub fn from_attributes(attributes: &[(String, String)]) -> Self {
+        let classes = attributes.iter().find(|(k, _)| k == "class");
+        let mut style = Self::from_classes(classes.map_or("", |(_, v)| v.as_str()));
+        style.font = attributes
+            .iter()
+            .find(|(k, _)| k == "font")
+            .map(|(_, v)| v.clone());
+        style
+    }
+
+    pub fn from_classes(classes: &str) -> Self {
+        let mut style = ResolvedStyle::default();
+        for class_name in classes.split_whitespace() {
+            style.resolve_class(class_name);
+        }
+        style.shadow_color = shadow_color(classes);
+        style
+    }
+
+    pub(crate) fn push(&mut self, declaration: Declaration) {
+        self.declarations.push(declaration);
+    }
+
+    fn resolve_class(&mut self, class_name: &str) {
+        // Handled while rendering: scrolling by render_node, as it needs a stateful element,
+        // order-N by render_children and grids by render_grid
+        if SCROLL_CLASSES.contains(&class_name)
+            || class_name.starts_with("order-")
+            || is_grid_class(class_name)
+        {
+            return;
+        }
+
+        if tailwind_method(class_name).is_some() {
+            self.push(Declaration::Class(class_name.to_string()));
+        }
+        // Dynamic colors
+        else if let Some(hex) = class_name.strip_prefix("bg-[#") {
+            self.resolve_color(class_name, hex, Declaration::Background);
+        } else if let Some(hex) = class_name.strip_prefix("text-color-[#") {
+            self.resolve_color(class_name, hex, Declaration::TextColor);
+        } else if let Some(hex) = class_name.strip_prefix("border-[#") {
+            self.resolve_color(class_name, hex, Declaration::BorderColor);
+        }
+        // Rounded with any px or rem value
+        else if let Some(suffix) = class_name.strip_prefix("rounded-") {
+            let corner = match suffix.split('-').next() {
+                Some("t") => Corner::Top,
+                Some("r") => Corner::Right,
+                Some("b") => Corner::Bottom,
+                Some("l") => Corner::Left,
+                Some("tl") => Corner::TopLeft,
+                Some("tr") => Corner::TopRight,
+                Some("br") => Corner::BottomRight,
+                Some("bl") => Corner::BottomLeft,
+                _ => Corner::All,
+            };
+            self.push(Declaration::Rounded(
+                corner,
+                Length::from_class_name(suffix),
+            ));
+        }
+        // Border with any px or rem value
+        else if let Some(suffix) = class_name.strip_prefix("border-") {
+            let length = Length::from_class_name(suffix);
+            let side = match suffix.split('-').next() {
+                Some("t") => Some(Side::Top),
+                Some("r") => Some(Side::Right),
+                Some("b") => Some(Side::Bottom),
+                Some("l") => Some(Side::Left),
+                _ => None,
+            };
+            self.push(match side {
+                Some(side) => Declaration::BorderWidth(side, length),
+                // Without a side, the corners are rounded instead
+                None => Declaration::Rounded(Corner::All, length),
+            });
+        } else if let Some(declaration) = [
+            ("top-[", Declaration::Top as fn(Length) -> Declaration),
+            ("left-[", Declaration::Left),
+            ("w-[", Declaration::Width),
+            ("h-[", Declaration::Height),
+        ]
+        .into_iter()
+        .find_map(|(prefix, declaration)| {
+            let suffix = class_name.strip_prefix(prefix)?;
+            Some(declaration(Length::from_class_name(
+                suffix.trim_end_matches(']'),
+            )))
+        }) {
+            self.push(declaration);
+        } else if let Some(opacity) = class_name.strip_prefix("opacity-") {
+            // Opacity is a percentage, anything outside 0 to 100 is a mistake
+            match opacity.parse::<f32>() {
+                Ok(opacity) if (0. ..=100.).contains(&opacity) => {
+                    self.push(Declaration::Opacity(opacity / 100.))
+                }
+                _ => report(Diagnostic::InvalidValue(class_name.to_string())),
+            }
+        } else if class_name == "hidden" {
+            self.push(Declaration::Hidden);
+        }
+        // Everything is a flex box already, inline-flex lays out the same as flex
+        else if class_name == "inline-flex" {
+            self.push(Declaration::Class("flex".to_string()));
+        } else if class_name.starts_with("z-") {
+            report(Diagnostic::Unsupported(format!(
+                "{} is not supported: GPUI paints in document order, use absolute positioning \
+                 or reorder the elements",
+                class_name
+            )));
+        } else if !(resolve_shadow_class(self, class_name)
+            || resolve_gradient_class(self, class_name)
+            // Text classes without a Styled method
+            || resolve_typography_class(self, class_name))
+        {
+            report(Diagnostic::UnrecognizedClass(class_name.to_string()));
+        }
+    }
+
+    fn resolve_color(
+        &mut self,
+        class_name: &str,
+        hex: &str,
+        declaration: fn(Color) -> Declaration,
+    ) {
+        match hex.strip_suffix(']').and_then(Color::from_hex) {
+            Some(color) => self.push(declaration(color)),
+            None => report(Diagnostic::InvalidValue(class_name.to_string())),
+        }
+    }
+}
+
+impl Color {
+    // RRGGBB or RRGGBBAA, without the #
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let valid =
+            (hex.len() == 6 || hex.len() == 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+        if !valid {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        Some(Color(if hex.len() == 6 {
+            value << 8 | 0xff
+        } else {
+            value
+        }))
+    }
+}
+
+impl Length {
+    // Length at the end of a class, like the "12px" of "rounded-12px"
+    pub fn from_class_name(class_name: &str) -> Self {
+        match length_in_class_name(class_name) {
+            (value, "rem") => Length::Rems(value),
+            (value, _) => Length::Pixels(value),
+        }
+    }
+}
+
+// Number and unit ("px" or "rem") of a length class; unrecognized units are 0px
+pub(crate) fn length_in_class_name(class_name: &str) -> (f32, &'static str) {
+    let numeric_part: String = class_name
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit() && *c != '.' && *c != '-')
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == '-')
+        .collect();
+
+    let unit_part: String = class_name
+        .chars()
+        .skip_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+
+    let rounded_value = numeric_part.parse::<f32>().unwrap_or_default();
+
+    match unit_part.as_str() {
+        "px" => (rounded_value, "px"),
+        "rem" => (rounded_value, "rem"),
+        _ => (0.0, "px"), // Default case for unrecognized units
+    }
+}
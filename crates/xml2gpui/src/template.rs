@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use crate::diagnostics::{report, Diagnostic};
+use crate::tree::{collect_templates, Component, Template};
+
+// Collects the <template> definitions of a tree and expands every use of them
+pub fn resolve_templates(mut root: Component) -> Component {
+    let templates = collect_templates(&mut root);
+    if templates.is_empty() {
+        return root;
+    }
+    expand_templates(&root, &templates)
+}
+
+// Replaces elements named after a template with the template body. In the body, {{prop}}
+// is replaced with the value of the matching attribute, <slot/> with the children of the
+// element and <slot name="x"/> with the children marked slot="x". Other attributes of the
+// element are carried over to the root of the body, with classes appended to its own.
+pub fn expand_templates(root: &Component, templates: &HashMap<String, Template>) -> Component {
+    let mut expanded = expand(root, templates, &mut Vec::new());
+    match expanded.len() {
+        1 => expanded.pop().unwrap(),
+        // A template with several root elements used as the document root
+        _ => Component {
+            children: expanded,
+            ..Component::empty()
+        },
+    }
+}
+
+fn expand(
+    component: &Component,
+    templates: &HashMap<String, Template>,
+    // Templates currently being expanded, to stop runaway recursion
+    stack: &mut Vec<String>,
+) -> Vec<Component> {
+    let children = component
+        .children
+        .iter()
+        .flat_map(|child| expand(child, templates, stack))
+        .collect();
+    apply_template(component, children, templates, stack)
+}
+
+// The element with its children, expanded already, or the body of the template it names
+fn apply_template(
+    component: &Component,
+    children: Vec<Component>,
+    templates: &HashMap<String, Template>,
+    stack: &mut Vec<String>,
+) -> Vec<Component> {
+    let Some(template) = templates.get(&component.elem) else {
+        return vec![Component {
+            elem: component.elem.clone(),
+            text: component.text.clone(),
+            attributes: component.attributes.clone(),
+            children,
+            location: component.location,
+        }];
+    };
+    if stack.contains(&template.name) {
+        report(Diagnostic::Element(format!(
+            "Template {} uses itself",
+            template.name
+        )));
+        return vec![];
+    }
+
+    let props: HashMap<&str, &str> = template
+        .props
+        .iter()
+        .map(|prop| (prop.as_str(), component.attribute(prop).unwrap_or_default()))
+        .collect();
+
+    // Children go to the slot named by their slot attribute, or to the default slot
+    let mut slots: HashMap<String, Vec<Component>> = HashMap::new();
+    if let Some(text) = &component.text {
+        slots
+            .entry(String::new())
+            .or_default()
+            .push(Component::text_node(text.clone()));
+    }
+    for mut child in children {
+        let slot = match child.attributes.iter().position(|(k, _)| k == "slot") {
+            Some(index) => child.attributes.remove(index).1,
+            None => String::new(),
+        };
+        slots.entry(slot).or_default().push(child);
+    }
+
+    // The remaining attributes of the element go to the root of the body
+    let root_attributes = (template.body.len() == 1).then(|| {
+        component
+            .attributes
+            .iter()
+            .filter(|(name, _)| !props.contains_key(name.as_str()))
+            .collect::<Vec<_>>()
+    });
+
+    // The body may use other templates. The slotted children are expanded already and are
+    // only moved into place.
+    stack.push(template.name.clone());
+    let mut body = Body {
+        props: &props,
+        slots: &mut slots,
+        templates,
+        stack,
+    };
+    let expanded = template
+        .body
+        .iter()
+        .flat_map(|node| body.expand(node, root_attributes.as_deref()))
+        .collect();
+    stack.pop();
+
+    expanded
+}
+
+// Expands a template body, substituting props and filling slots on the way
+struct Body<'a> {
+    props: &'a HashMap<&'a str, &'a str>,
+    slots: &'a mut HashMap<String, Vec<Component>>,
+    templates: &'a HashMap<String, Template>,
+    stack: &'a mut Vec<String>,
+}
+
+impl Body<'_> {
+    fn expand(
+        &mut self,
+        node: &Component,
+        root_attributes: Option<&[&(String, String)]>,
+    ) -> Vec<Component> {
+        let mut children = Vec::new();
+        for child in &node.children {
+            if child.elem != "slot" {
+                children.extend(self.expand(child, None));
+                continue;
+            }
+            let name = child.attribute("name").unwrap_or_default();
+            match self.slots.remove(name) {
+                Some(slotted) => children.extend(slotted),
+                // Nothing slotted, use the fallback content of the slot
+                None => {
+                    for fallback in &child.children {
+                        children.extend(self.expand(fallback, None));
+                    }
+                }
+            }
+        }
+
+        let mut instance = substituted(node, self.props);
+        for (name, value) in root_attributes.unwrap_or_default() {
+            match instance.attributes.iter_mut().find(|(k, _)| k == name) {
+                Some((_, existing)) if name == "class" => {
+                    *existing = format!("{} {}", existing, value);
+                }
+                Some(_) => {}
+                None => instance.attributes.push((name.clone(), value.clone())),
+            }
+        }
+        apply_template(&instance, children, self.templates, self.stack)
+    }
+}
+
+// Copies a template body node with props substituted and slots filled, for the rows of
+// virtual lists
+#[cfg(feature = "render")]
+pub(crate) fn instantiate(
+    node: &Component,
+    props: &HashMap<&str, &str>,
+    slots: &mut HashMap<String, Vec<Component>>,
+) -> Component {
+    let mut children = Vec::new();
+    for child in &node.children {
+        if child.elem == "slot" {
+            let name = child.attribute("name").unwrap_or_default();
+            match slots.remove(name) {
+                Some(slotted) => children.extend(slotted),
+                // Nothing slotted, use the fallback content of the slot
+                None => children.extend(
+                    child
+                        .children
+                        .iter()
+                        .map(|fallback| instantiate(fallback, props, slots)),
+                ),
+            }
+        } else {
+            children.push(instantiate(child, props, slots));
+        }
+    }
+
+    Component {
+        children,
+        ..substituted(node, props)
+    }
+}
+
+// The node without its children, with props substituted in its text and attributes
+fn substituted(node: &Component, props: &HashMap<&str, &str>) -> Component {
+    Component {
+        elem: node.elem.clone(),
+        text: node.text.as_deref().map(|text| substitute(text, props)),
+        attributes: node
+            .attributes
+            .iter()
+            .map(|(k, v)| (k.clone(), substitute(v, props)))
+            .collect(),
+        children: Vec::new(),
+        location: node.location,
+    }
+}
+
+// Replaces {{prop}} with prop values. Placeholders that don't name a prop are left alone.
+fn substitute(value: &str, props: &HashMap<&str, &str>) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + end].trim();
+        result.push_str(&rest[..start]);
+        match props.get(name) {
+            Some(prop) => result.push_str(prop),
+            None => result.push_str(&rest[start..start + end + 2]),
+        }
+        rest = &rest[start + end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,155 @@
+#[cfg(feature = "render")]
+use gpui::*;
+
+use std::ops::Range;
+
+use crate::style::ResolvedStyle;
+use crate::theme::Theme;
+use crate::tree::Component;
+
+// Renders a run of inline nodes (text and elements like <b> or <a>) as a single paragraph,
+// so it wraps as a whole instead of each piece becoming a separate flex child. Returns None
+// if the run is only whitespace.
+#[cfg(feature = "render")]
+pub(crate) fn render_inline<'a>(
+    nodes: impl IntoIterator<Item = &'a Component>,
+    id: SharedString,
+    base_style: &TextStyle,
+    theme: &Theme,
+) -> Option<AnyElement> {
+    let paragraph = build_paragraph(nodes, theme)?;
+    let runs = paragraph
+        .runs
+        .iter()
+        .map(|(len, style)| run_style(base_style, style).to_run(*len))
+        .collect();
+
+    let styled_text = StyledText::new(paragraph.text).with_runs(runs);
+    if paragraph.links.is_empty() {
+        return Some(styled_text.into_any_element());
+    }
+
+    let (ranges, urls): (Vec<Range<usize>>, Vec<String>) = paragraph.links.into_iter().unzip();
+    Some(
+        InteractiveText::new(id, styled_text)
+            .on_click(ranges, move |ix, cx| cx.open_url(&urls[ix]))
+            .into_any_element(),
+    )
+}
+
+// Text style of a run, the inherited style with the style of its inline elements applied.
+// Text has no box, so only what they set on the text style and the background count.
+#[cfg(feature = "render")]
+pub(crate) fn run_style(base_style: &TextStyle, style: &ResolvedStyle) -> TextStyle {
+    let mut text_style = base_style.clone();
+    let mut element = style.apply(div());
+    if let Some(refinement) = element.text_style().clone() {
+        text_style.refine(&refinement);
+    }
+    if let Some(Fill::Color(color)) = element.style().background {
+        text_style.background_color = Some(color);
+    }
+    text_style
+}
+
+// Text of a paragraph, with the runs of it that share a style (length in bytes and the style
+// of the inline elements around it) and the ranges that link somewhere
+#[derive(Default)]
+pub struct Paragraph {
+    pub text: String,
+    pub runs: Vec<(usize, ResolvedStyle)>,
+    pub links: Vec<(Range<usize>, String)>,
+}
+
+// Lays out the text of a run of inline nodes. Inline elements are styled like blocks, with
+// the classes of the theme and their own. Returns None if it is only whitespace.
+pub fn build_paragraph<'a>(
+    nodes: impl IntoIterator<Item = &'a Component>,
+    theme: &Theme,
+) -> Option<Paragraph> {
+    let mut paragraph = Paragraph::default();
+    for node in nodes {
+        paragraph.push_node(node, &ResolvedStyle::default(), theme);
+    }
+    paragraph.trim_end();
+    (!paragraph.text.is_empty()).then_some(paragraph)
+}
+
+impl Paragraph {
+    fn push_str(&mut self, text: &str, style: &ResolvedStyle) {
+        // Whitespace at the start of a paragraph is insignificant, and whitespace where one
+        // node ends and the next starts collapses into one space like within a node
+        let text = if self.text.is_empty() || self.text.ends_with(char::is_whitespace) {
+            text.trim_start()
+        } else {
+            text
+        };
+        if text.is_empty() {
+            return;
+        }
+        self.text.push_str(text);
+        self.runs.push((text.len(), style.clone()));
+    }
+
+    fn push_node(&mut self, node: &Component, style: &ResolvedStyle, theme: &Theme) {
+        if node.is_text_node() {
+            self.push_str(node.text.as_deref().unwrap_or_default(), style);
+            return;
+        }
+        if node.elem == "br" {
+            self.text.push('\n');
+            self.runs.push((1, style.clone()));
+            return;
+        }
+
+        let style = nested(
+            style,
+            ResolvedStyle::resolve(&node.elem, &node.attributes, theme),
+        );
+        let start = self.text.len();
+        if let Some(text) = &node.text {
+            self.push_str(text, &style);
+        }
+        for child in &node.children {
+            self.push_node(child, &style, theme);
+        }
+
+        if node.elem == "a" {
+            if let Some(href) = node.attribute("href") {
+                self.links.push((start..self.text.len(), href.to_string()));
+            }
+        }
+    }
+
+    fn trim_end(&mut self) {
+        let mut excess = self.text.len() - self.text.trim_end().len();
+        self.text.truncate(self.text.len() - excess);
+        while excess > 0 {
+            let Some((len, _)) = self.runs.last_mut() else {
+                break;
+            };
+            if *len > excess {
+                *len -= excess;
+                break;
+            }
+            excess -= *len;
+            self.runs.pop();
+        }
+        let len = self.text.len();
+        self.links.retain_mut(|(range, _)| {
+            range.end = range.end.min(len);
+            range.start < range.end
+        });
+    }
+}
+
+// Style of the text in an inline element: its own declarations follow the inherited ones, so
+// that they override them
+fn nested(outer: &ResolvedStyle, inner: ResolvedStyle) -> ResolvedStyle {
+    let mut style = outer.clone();
+    style.declarations.extend(inner.declarations);
+    if inner.font.is_some() {
+        style.font = inner.font;
+    }
+    style
+}
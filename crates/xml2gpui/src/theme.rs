@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+// Default classes for elements, the "user agent stylesheet". They are applied before the
+// element's own classes, so classes in the markup always win.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    element_classes: HashMap<String, String>,
+    // Font families of the font-sans, font-serif and font-mono classes
+    font_families: HashMap<String, String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let element_classes = [
+            ("h1", "text-3xl font-bold mt-6 mb-4"),
+            ("h2", "text-2xl font-bold mt-5 mb-3"),
+            ("h3", "text-xl font-semibold mt-4 mb-2"),
+            ("h4", "text-lg font-semibold mt-4 mb-2"),
+            ("h5", "text-base font-semibold mt-3 mb-1"),
+            ("h6", "text-sm font-semibold mt-3 mb-1"),
+            ("p", "mb-4"),
+            ("ul", "flex flex-col mb-4"),
+            ("ol", "flex flex-col mb-4"),
+            ("li", "flex-1"),
+            ("hr", "w-full border-t-1 border-[#d1d5db] my-4"),
+            ("br", "h-4"),
+            // Inline elements style the text they contain
+            ("b", "font-bold"),
+            ("strong", "font-bold"),
+            ("i", "italic"),
+            ("em", "italic"),
+            ("u", "underline"),
+            ("s", "line-through"),
+            ("del", "line-through"),
+            ("code", "font-mono bg-[#8080802e]"),
+            ("a", "text-color-[#2563eb] underline"),
+            // uniform_list needs a definite height to know how many rows are visible
+            ("virtual-list", "w-full h-full"),
+            (
+                "button",
+                "flex flex-row items-center justify-center px-3 py-1 rounded-md border-1 border-[#d1d5db] bg-[#f3f4f6] cursor-pointer",
+            ),
+            ("checkbox", "flex flex-row items-center cursor-pointer"),
+            ("toggle", "flex flex-row items-center cursor-pointer"),
+            (
+                "input",
+                "flex flex-row items-center px-2 py-1 rounded-md border-1 border-[#d1d5db] bg-[#ffffff] cursor-text",
+            ),
+            (
+                "select",
+                "flex flex-col rounded-md border-1 border-[#d1d5db] bg-[#ffffff] cursor-pointer",
+            ),
+        ]
+        .into_iter()
+        .map(|(elem, classes)| (elem.to_string(), classes.to_string()))
+        .collect();
+
+        Theme {
+            element_classes,
+            font_families: default_font_families(),
+        }
+    }
+}
+
+fn default_font_families() -> HashMap<String, String> {
+    [
+        ("font-sans", "Helvetica"),
+        ("font-serif", "Times New Roman"),
+        ("font-mono", "Menlo"),
+    ]
+    .into_iter()
+    .map(|(class, family)| (class.to_string(), family.to_string()))
+    .collect()
+}
+
+impl Theme {
+    // Theme without any default classes
+    pub fn empty() -> Self {
+        Theme {
+            element_classes: HashMap::new(),
+            font_families: default_font_families(),
+        }
+    }
+
+    pub fn with_element_classes(mut self, elem: &str, classes: &str) -> Self {
+        self.element_classes
+            .insert(elem.to_string(), classes.to_string());
+        self
+    }
+
+    pub fn element_classes(&self, elem: &str) -> Option<&str> {
+        self.element_classes.get(elem).map(String::as_str)
+    }
+
+    // Family used for "font-sans", "font-serif" or "font-mono"
+    pub fn with_font_family(mut self, class: &str, family: &str) -> Self {
+        self.font_families
+            .insert(class.to_string(), family.to_string());
+        self
+    }
+
+    pub fn font_family(&self, class: &str) -> Option<&str> {
+        self.font_families.get(class).map(String::as_str)
+    }
+
+    // Attributes of an element with the theme classes placed in front of its own classes, and
+    // a font attribute for the font family class it has
+    pub fn apply(&self, elem: &str, attributes: &[(String, String)]) -> Vec<(String, String)> {
+        let mut attributes = attributes.to_vec();
+        if let Some(theme_classes) = self.element_classes(elem) {
+            match attributes.iter_mut().find(|(k, _)| k == "class") {
+                Some((_, classes)) => *classes = format!("{} {}", theme_classes, classes),
+                None => attributes.push(("class".to_string(), theme_classes.to_string())),
+            }
+        }
+
+        // An explicit font attribute wins over the class
+        let family = attributes
+            .iter()
+            .find(|(k, _)| k == "class")
+            .and_then(|(_, classes)| {
+                classes
+                    .split_whitespace()
+                    .rev()
+                    .find_map(|class| self.font_family(class))
+            });
+        if let Some(family) = family {
+            if !attributes.iter().any(|(k, _)| k == "font") {
+                attributes.push(("font".to_string(), family.to_string()));
+            }
+        }
+        attributes
+    }
+}
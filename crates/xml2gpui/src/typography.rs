@@ -0,0 +1,80 @@
+use crate::diagnostics::{report, Diagnostic};
+use crate::style::{Declaration, LineHeight, ResolvedStyle};
+
+// Font size of text-4xl..text-9xl in rems, continuing the text-xs..text-3xl scale
+const TEXT_SIZES: &[(&str, f32)] = &[
+    ("text-4xl", 2.25),
+    ("text-5xl", 3.),
+    ("text-6xl", 3.75),
+    ("text-7xl", 4.5),
+    ("text-8xl", 6.),
+    ("text-9xl", 8.),
+];
+
+// Line height relative to the font size
+const LEADING: &[(&str, f32)] = &[
+    ("leading-none", 1.),
+    ("leading-tight", 1.25),
+    ("leading-snug", 1.375),
+    ("leading-normal", 1.5),
+    ("leading-relaxed", 1.625),
+    ("leading-loose", 2.),
+];
+
+// Resolves text related classes that have no Styled method. Returns false if the class isn't
+// one of them.
+pub(crate) fn resolve_typography_class(style: &mut ResolvedStyle, class_name: &str) -> bool {
+    if let Some((_, size)) = TEXT_SIZES.iter().find(|(name, _)| *name == class_name) {
+        style.push(Declaration::FontSize(*size));
+        return true;
+    }
+    if let Some((_, height)) = LEADING.iter().find(|(name, _)| *name == class_name) {
+        style.push(Declaration::LineHeight(LineHeight::Relative(*height)));
+        return true;
+    }
+    // leading-3..leading-10 are fixed line heights in quarter rems
+    if let Some(steps) = class_name
+        .strip_prefix("leading-")
+        .and_then(|steps| steps.parse::<f32>().ok())
+    {
+        if steps.is_finite() && steps >= 0. {
+            style.push(Declaration::LineHeight(LineHeight::Rems(steps / 4.)));
+        } else {
+            report(Diagnostic::InvalidValue(class_name.to_string()));
+        }
+        return true;
+    }
+
+    match class_name {
+        "italic" => style.push(Declaration::Italic(true)),
+        "not-italic" => style.push(Declaration::Italic(false)),
+        "underline" => style.push(Declaration::Underline(true)),
+        "no-underline" => style.push(Declaration::Underline(false)),
+        "whitespace-nowrap" => style.push(Declaration::Nowrap(true)),
+        "whitespace-normal" => style.push(Declaration::Nowrap(false)),
+        // Without text overflow support the text is cut off instead of ending with an ellipsis
+        "truncate" => style.push(Declaration::Truncate),
+        // Font families come from the theme, see Theme::apply
+        "font-mono" | "font-sans" | "font-serif" => {}
+        "text-left" | "text-center" | "text-right" | "text-justify" => {
+            report(Diagnostic::Unsupported(format!(
+                "{} is not supported: the pinned GPUI revision has no text alignment",
+                class_name
+            )))
+        }
+        "line-through" => report(Diagnostic::Unsupported(
+            "line-through is not supported: the pinned GPUI revision has no strikethrough"
+                .to_string(),
+        )),
+        _ if class_name.starts_with("tracking-") => report(Diagnostic::Unsupported(format!(
+            "{} is not supported: the pinned GPUI revision has no letter spacing",
+            class_name
+        ))),
+        _ if class_name.starts_with("line-clamp-") => report(Diagnostic::Unsupported(format!(
+            "{} is not supported: the pinned GPUI revision can't clamp lines",
+            class_name
+        ))),
+        _ => return false,
+    }
+    true
+}
@@ -0,0 +1,369 @@
+use gpui::*;
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[cfg(feature = "hot-reload")]
+use futures::{channel::mpsc::unbounded, StreamExt};
+#[cfg(feature = "hot-reload")]
+use notify::{Config, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+#[cfg(feature = "hot-reload")]
+use std::collections::HashSet;
+#[cfg(feature = "hot-reload")]
+use std::time::Duration;
+
+use crate::diagnostics;
+use crate::diff::{diff, Change};
+use crate::document::{load_document, Document, FsLoader, XmlLoader};
+use crate::form::FormState;
+use crate::list::ListSource;
+use crate::render::{render_component, ComponentType, RenderState, RenderView};
+use crate::template::resolve_templates;
+use crate::theme::Theme;
+use crate::tree::{Component, ParseError};
+
+// Change events arriving within this window are coalesced into a single reload
+#[cfg(feature = "hot-reload")]
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(50);
+
+// View that renders a markup file. With the "hot-reload" feature the file and everything it
+// includes is watched, and the tree is re-parsed in the background whenever one of them
+// changes. If the markup fails to parse, the last good tree stays on screen with an error
+// overlay on top.
+pub struct XmlView {
+    path: PathBuf,
+    loader: Arc<dyn XmlLoader>,
+    files: Vec<PathBuf>,
+    root: Arc<Component>,
+    error: Option<ParseError>,
+    pub(crate) state: RenderState,
+    _form_subscription: Option<Subscription>,
+    // None if the loader's files can't be watched
+    #[cfg(feature = "hot-reload")]
+    _watch_task: Option<Task<()>>,
+}
+
+impl XmlView {
+    pub fn new(path: impl Into<PathBuf>, cx: &mut WindowContext) -> View<Self> {
+        Self::with_loader(path, Arc::new(FsLoader), cx)
+    }
+
+    pub fn with_loader(
+        path: impl Into<PathBuf>,
+        loader: Arc<dyn XmlLoader>,
+        cx: &mut WindowContext,
+    ) -> View<Self> {
+        let path = path.into();
+        let result = load(&path, loader.as_ref());
+        Self::from_result(path, loader, result, cx)
+    }
+
+    // View of a document loaded beforehand, e.g. from a cache. Reloads read the files through
+    // `loader`.
+    pub fn with_document(
+        path: impl Into<PathBuf>,
+        loader: Arc<dyn XmlLoader>,
+        document: Document,
+        cx: &mut WindowContext,
+    ) -> View<Self> {
+        Self::from_result(path.into(), loader, Ok(expand(document)), cx)
+    }
+
+    fn from_result(
+        path: PathBuf,
+        loader: Arc<dyn XmlLoader>,
+        result: Result<Document, ParseError>,
+        cx: &mut WindowContext,
+    ) -> View<Self> {
+        let (root, files, error) = match result {
+            Ok(Document { root, files }) => (root, files, None),
+            Err(error) => (Component::empty(), vec![path.clone()], Some(error)),
+        };
+
+        cx.new_view(
+            |#[cfg_attr(not(feature = "hot-reload"), allow(unused_variables))] cx| Self {
+                #[cfg(feature = "hot-reload")]
+                _watch_task: loader
+                    .is_watchable()
+                    .then(|| watch_files(path.clone(), loader.clone(), files.clone(), cx)),
+                path,
+                loader,
+                files,
+                root: Arc::new(root),
+                error,
+                state: RenderState::new(),
+                _form_subscription: None,
+            },
+        )
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn root(&self) -> Arc<Component> {
+        self.root.clone()
+    }
+
+    // The rendered file followed by every file it includes
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    pub fn error(&self) -> Option<&ParseError> {
+        self.error.as_ref()
+    }
+
+    // Replaces the default classes used for elements like <h1> and <p>
+    pub fn set_theme(&mut self, theme: Theme, cx: &mut ViewContext<Self>) {
+        self.state.theme = theme;
+        cx.notify();
+    }
+
+    // Values and handlers for the form controls in the markup. The view re-renders whenever
+    // the form state changes.
+    pub fn set_form_state(&mut self, form: Model<FormState>, cx: &mut ViewContext<Self>) {
+        self._form_subscription = Some(cx.observe(&form, |_, _, cx| cx.notify()));
+        self.state.form = Some(form);
+        cx.notify();
+    }
+
+    // Rows for <virtual-list source="name">. Call cx.notify() on the view when they change.
+    pub fn set_list_source(
+        &mut self,
+        name: impl Into<String>,
+        source: Rc<dyn ListSource>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.state.set_list_source(name, source);
+        cx.notify();
+    }
+
+    // Scrolls the scrollable element containing the element with this id so it is visible
+    pub fn scroll_to(&mut self, id: impl Into<String>, cx: &mut ViewContext<Self>) {
+        self.state.scroll_to(id);
+        cx.notify();
+    }
+
+    // Changes the rendered tree in place (see edit.rs) and re-renders it, without going through
+    // the markup files. The changes last until the tree is reloaded from the files. The tree has
+    // its templates expanded already, and added elements aren't expanded: an element named
+    // after a template renders as an empty div, without its children.
+    pub fn update_tree(&mut self, cx: &mut ViewContext<Self>, update: impl FnOnce(&mut Component)) {
+        let root = Arc::make_mut(&mut self.root);
+        update(root);
+        self.state.retain(root);
+        cx.notify();
+    }
+
+    pub fn reload(&mut self, cx: &mut ViewContext<Self>) {
+        let result = load(&self.path, self.loader.as_ref()).map(|document| {
+            let changes = diff(&self.root, &document.root);
+            (document, changes)
+        });
+        self.apply_reload(result, cx);
+    }
+
+    // Swaps in a freshly loaded tree, or keeps the last good one and shows the error
+    fn apply_reload(
+        &mut self,
+        result: Result<(Document, Vec<Change>), ParseError>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match result {
+            Ok((document, changes)) if changes.is_empty() && self.error.is_none() => {
+                // Nothing visible changed (e.g. only comments or formatting), skip the re-render
+                self.files = document.files;
+                return;
+            }
+            Ok((Document { root, files }, _)) => {
+                diagnostics::forget_printed();
+                self.state.retain(&root);
+                self.root = Arc::new(root);
+                self.files = files;
+                self.error = None;
+            }
+            Err(error) => {
+                eprintln!("{}", error);
+                self.error = Some(error);
+            }
+        }
+        cx.notify();
+    }
+
+    fn render_error_overlay(error: &ParseError, cx: &mut ViewContext<Self>) -> Div {
+        div()
+            .absolute()
+            .top_0()
+            .left_0()
+            .w_full()
+            .flex()
+            .flex_row()
+            .justify_between()
+            .items_start()
+            .p_4()
+            .bg(rgba(0xb91c1ce6))
+            .text_color(rgb(0xffffff))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .font_weight(FontWeight::BOLD)
+                            .child("Failed to parse markup"),
+                    )
+                    .child(error.to_string()),
+            )
+            .child(
+                div()
+                    .id("xml2gpui-error-dismiss")
+                    .px_2()
+                    .cursor_pointer()
+                    .child("×")
+                    .on_click(cx.listener(|this, _, cx| {
+                        this.error = None;
+                        cx.notify();
+                    })),
+            )
+    }
+}
+
+impl RenderView for XmlView {
+    fn render_state(&mut self) -> &mut RenderState {
+        &mut self.state
+    }
+}
+
+impl Render for XmlView {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Root element must be a div
+        let element = match render_component(&self.root, &mut self.state, cx) {
+            ComponentType::Img(_) | ComponentType::Svg(_) => div()
+                .child("Error: root element must be a div!")
+                .into_any_element(),
+            element => element.into_any_element(),
+        };
+
+        match &self.error {
+            Some(error) => div()
+                .relative()
+                .size_full()
+                .child(element)
+                .child(Self::render_error_overlay(error, cx))
+                .into_any_element(),
+            None => element,
+        }
+    }
+}
+
+// Loads the document and expands the templates it defines
+fn load(path: &Path, loader: &dyn XmlLoader) -> Result<Document, ParseError> {
+    load_document(path, loader).map(expand)
+}
+
+fn expand(mut document: Document) -> Document {
+    document.root = resolve_templates(document.root);
+    document
+}
+
+#[cfg(feature = "hot-reload")]
+fn watch_files(
+    path: PathBuf,
+    loader: Arc<dyn XmlLoader>,
+    files: Vec<PathBuf>,
+    cx: &mut ViewContext<XmlView>,
+) -> Task<()> {
+    cx.spawn(|this, mut cx| async move {
+        let (tx, mut rx) = unbounded();
+
+        // The watcher must stay alive for as long as we are listening, so it lives in this task
+        let mut watcher = match RecommendedWatcher::new(
+            move |res| {
+                tx.unbounded_send(res).ok();
+            },
+            Config::default(),
+        ) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("watch error: {:?}", e);
+                return;
+            }
+        };
+
+        // Editors often save by replacing the file, which drops a watch on the file itself.
+        // Watch the containing directories instead and filter events by path.
+        let mut watched_dirs = HashSet::new();
+        let mut watched_files = HashSet::new();
+        let mut watch = |files: &[PathBuf], watched_files: &mut HashSet<PathBuf>| {
+            watched_files.clear();
+            for file in files {
+                let file = std::fs::canonicalize(file).unwrap_or(file.clone());
+                let dir = match file.parent() {
+                    Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+                    _ => PathBuf::from("."),
+                };
+                if watched_dirs.insert(dir.clone()) {
+                    if let Err(e) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                        eprintln!("watch error: {:?}", e);
+                    }
+                }
+                watched_files.insert(file);
+            }
+        };
+        watch(&files, &mut watched_files);
+
+        while let Some(res) = rx.next().await {
+            let is_change = match res {
+                Ok(event) => {
+                    matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                        && event.paths.iter().any(|p| watched_files.contains(p))
+                }
+                Err(e) => {
+                    eprintln!("watch error: {:?}", e);
+                    false
+                }
+            };
+            if !is_change {
+                continue;
+            }
+
+            // Let the burst of events from a single save settle, then drop whatever queued up
+            cx.background_executor().timer(RELOAD_DEBOUNCE).await;
+            while let Ok(Some(_)) = rx.try_next() {}
+
+            let Ok(old_root) = this.update(&mut cx, |this, _| this.root.clone()) else {
+                break;
+            };
+            let reload_path = path.clone();
+            let reload_loader = loader.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move {
+                    load(&reload_path, reload_loader.as_ref()).map(|document| {
+                        let changes = diff(&old_root, &document.root);
+                        (document, changes)
+                    })
+                })
+                .await;
+
+            // Includes may have been added or removed, keep watching whatever the document
+            // consists of now. On errors, also watch the file that failed so fixing it reloads.
+            match &result {
+                Ok((document, _)) => watch(&document.files, &mut watched_files),
+                Err(error) => {
+                    let mut files: Vec<PathBuf> = watched_files.iter().cloned().collect();
+                    files.extend(error.file.clone());
+                    watch(&files, &mut watched_files);
+                }
+            }
+
+            let updated = this.update(&mut cx, |this, cx| this.apply_reload(result, cx));
+            if updated.is_err() {
+                // View was dropped
+                break;
+            }
+        }
+    })
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "cache")]
+
+use std::path::{Path, PathBuf};
+
+use xml2gpui::cache::{load_document_cached, CachedDocument};
+use xml2gpui::document::{load_document, EmbeddedLoader, FsLoader};
+
+// An empty directory of its own for each test
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("xml2gpui-cache-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn cached(loader: &EmbeddedLoader) -> CachedDocument {
+    let document = load_document(Path::new("main.xml"), loader).unwrap();
+    CachedDocument::new(document, loader).unwrap()
+}
+
+fn cache_entries(cache_dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(cache_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect()
+}
+
+#[test]
+fn documents_read_back_from_bytes() {
+    let loader = EmbeddedLoader::new()
+        .with_file(
+            "main.xml",
+            r#"<div class="flex"><include src="header.xml"/></div>"#,
+        )
+        .with_file("header.xml", "<h1>Title</h1>");
+    let cached = cached(&loader);
+    let read = CachedDocument::from_bytes(&cached.to_bytes()).unwrap();
+    assert_eq!(read.document.root, cached.document.root);
+    assert_eq!(read.document.files, cached.document.files);
+    assert_eq!(read.hashes, cached.hashes);
+}
+
+#[test]
+fn other_bytes_are_not_read() {
+    let loader = EmbeddedLoader::new().with_file("main.xml", "<div/>");
+    let bytes = cached(&loader).to_bytes();
+    assert!(CachedDocument::from_bytes(b"").is_none());
+    assert!(CachedDocument::from_bytes(&bytes[1..]).is_none());
+    assert!(CachedDocument::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+
+    // An entry in another format version
+    let mut other_version = bytes.clone();
+    other_version[b"xml2gpui".len()] ^= 0xff;
+    assert!(CachedDocument::from_bytes(&other_version).is_none());
+}
+
+#[test]
+fn documents_are_current_until_a_file_changes() {
+    let loader = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<div><include src="header.xml"/></div>"#)
+        .with_file("header.xml", "<h1>Title</h1>");
+    let cached = cached(&loader);
+    assert!(cached.is_current(&loader));
+
+    let changed_include = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<div><include src="header.xml"/></div>"#)
+        .with_file("header.xml", "<h1>Other title</h1>");
+    assert!(!cached.is_current(&changed_include));
+
+    let missing_include =
+        EmbeddedLoader::new().with_file("main.xml", r#"<div><include src="header.xml"/></div>"#);
+    assert!(!cached.is_current(&missing_include));
+}
+
+#[test]
+fn cached_documents_are_reused_until_an_include_changes() {
+    let dir = temp_dir("include");
+    let cache_dir = dir.join("cache");
+    let main = dir.join("main.xml");
+    std::fs::write(&main, r#"<div><include src="header.xml"/></div>"#).unwrap();
+    std::fs::write(dir.join("header.xml"), "<h1>Title</h1>").unwrap();
+
+    let document = load_document_cached(&main, &FsLoader, &cache_dir).unwrap();
+    assert_eq!(document.root.children[0].text.as_deref(), Some("Title"));
+    let entries = cache_entries(&cache_dir);
+    assert_eq!(entries.len(), 1);
+
+    // Change the stored tree to tell a reused entry from a parsed file
+    let mut entry = CachedDocument::from_bytes(&std::fs::read(&entries[0]).unwrap()).unwrap();
+    entry.document.root.children[0].set_text("From the cache");
+    std::fs::write(&entries[0], entry.to_bytes()).unwrap();
+    let document = load_document_cached(&main, &FsLoader, &cache_dir).unwrap();
+    assert_eq!(
+        document.root.children[0].text.as_deref(),
+        Some("From the cache")
+    );
+
+    // The main file is the same, so the entry is found but no longer current
+    std::fs::write(dir.join("header.xml"), "<h1>New title</h1>").unwrap();
+    let document = load_document_cached(&main, &FsLoader, &cache_dir).unwrap();
+    assert_eq!(document.root.children[0].text.as_deref(), Some("New title"));
+    assert_eq!(cache_entries(&cache_dir), entries);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,75 @@
+use xml2gpui::codegen::generate;
+use xml2gpui::diagnostics::{collect_diagnostics, Diagnostic};
+use xml2gpui::theme::Theme;
+use xml2gpui::tree::try_parse_xml;
+
+// The generated code of tests/codegen/fixture.xml, compiled against GPUI
+#[cfg(feature = "render")]
+mod fixture {
+    include!("codegen/fixture.rs");
+}
+
+fn generated(xml: &str) -> String {
+    generate(&try_parse_xml(xml).unwrap(), "view", &Theme::default())
+}
+
+#[test]
+fn ordered_lists_are_numbered_from_start() {
+    let code = generated(r#"<ol start="5"><li>a</li><li>b</li><li>c</li></ol>"#);
+    for marker in ["\"5.\"", "\"6.\"", "\"7.\""] {
+        assert!(code.contains(marker), "{} missing in\n{}", marker, code);
+    }
+    assert!(!code.contains("\"8.\""), "{}", code);
+}
+
+#[test]
+fn line_breaks_on_their_own_are_empty_lines() {
+    let code = generated("<div><p>a</p><br/><p>b</p></div>");
+    // The theme gives <br> a height
+    assert!(code.contains(".h_4()"), "{}", code);
+}
+
+#[test]
+fn grid_classes_with_invalid_numbers_are_reported() {
+    let (code, diagnostics) =
+        collect_diagnostics(|| generated(r#"<div class="grid grid-rows-4000000000"/>"#));
+    assert_eq!(
+        diagnostics,
+        [Diagnostic::InvalidValue("grid-rows-4000000000".to_string())]
+    );
+    // Clamped to the most rows a grid can have
+    assert_eq!(code.matches("grid_row(").count(), 1000 + 1);
+
+    let (_, diagnostics) = collect_diagnostics(|| {
+        generated(r#"<div class="grid grid-cols-abc"><p class="col-span-x">a</p></div>"#)
+    });
+    assert_eq!(
+        diagnostics,
+        [
+            Diagnostic::InvalidValue("grid-cols-abc".to_string()),
+            Diagnostic::InvalidValue("col-span-x".to_string()),
+        ]
+    );
+}
+
+// Covers every kind of declaration, the grid helpers and paragraphs. Run with
+// XML2GPUI_UPDATE_SNAPSHOTS=1 to regenerate fixture.rs after changing the generator.
+#[test]
+fn fixture_generates_the_checked_in_code() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/codegen");
+    let xml = std::fs::read_to_string(dir.join("fixture.xml")).unwrap();
+    let code = generate(&try_parse_xml(&xml).unwrap(), "fixture", &Theme::default());
+    if std::env::var("XML2GPUI_UPDATE_SNAPSHOTS").is_ok_and(|value| value == "1") {
+        std::fs::write(dir.join("fixture.rs"), &code).unwrap();
+    }
+    assert_eq!(
+        code,
+        std::fs::read_to_string(dir.join("fixture.rs")).unwrap()
+    );
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn generated_code_builds_an_element() {
+    let _: fn(&mut gpui::WindowContext) -> gpui::AnyElement = fixture::fixture;
+}
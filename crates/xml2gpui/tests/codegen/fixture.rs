@@ -0,0 +1,193 @@
+// Generated by xml2gpui, do not edit
+
+use gpui::*;
+
+#[allow(clippy::single_range_in_vec_init)]
+pub fn fixture(cx: &mut WindowContext) -> AnyElement {
+    let style = cx.text_style();
+    let element = {
+        let mut element = div()
+            .font("Inter")
+            .flex()
+            .flex_col()
+            .gap_2()
+            .p_4()
+            .bg(rgba(0xf8fafcff));
+        let mut style = style.clone();
+        if let Some(refinement) = element.text_style().clone() {
+            style.refine(&refinement);
+        }
+        element
+            .child({
+                let mut element = div().text_3xl().font_weight(FontWeight::BOLD).mt_6().mb_4();
+                element.text_style().get_or_insert_with(Default::default).font_size = Some(rems(2.25).into());
+                element.text_style().get_or_insert_with(Default::default).line_height = Some(relative(1.25));
+                element.child("Report")
+            })
+            .child({
+                let mut element = div().rounded(px(8.0)).border_color(rgba(0xe2e8f0ff));
+                element.style().box_shadow = Some(
+                    [
+                        BoxShadow {
+                            color: rgba(0x00000040).into(),
+                            offset: point(px(0.0), px(4.0)),
+                            blur_radius: px(12.0),
+                            spread_radius: px(0.0),
+                        },
+                    ]
+                    .into_iter()
+                    .collect(),
+                );
+                if let Some(shadows) = &mut element.style().box_shadow {
+                    for shadow in shadows.iter_mut() {
+                        shadow.color = rgba(0x2563eb80).into();
+                    }
+                }
+                let mut style = style.clone();
+                if let Some(refinement) = element.text_style().clone() {
+                    style.refine(&refinement);
+                }
+                element
+                    .id("card")
+                    .child({
+                        let mut element = div().mb_4();
+                        element.text_style().get_or_insert_with(Default::default).font_style = Some(FontStyle::Italic);
+                        element.text_style().get_or_insert_with(Default::default).underline = Some(UnderlineStyle { thickness: px(1.), ..Default::default() });
+                        element = element.text_color(rgba(0x334155ff));
+                        let mut style = style.clone();
+                        if let Some(refinement) = element.text_style().clone() {
+                            style.refine(&refinement);
+                        }
+                        element
+                            .child(
+                                InteractiveText::new(
+                                    "/1/0/0",
+                                    StyledText::new("Some bold and a link.").with_runs(vec![
+                                        style.to_run(5),
+                                        {
+                                            let mut style = style.clone();
+                                            let mut element = div().font_weight(FontWeight::BOLD);
+                                            if let Some(refinement) = element.text_style().clone() {
+                                                style.refine(&refinement);
+                                            }
+                                            style
+                                        }.to_run(4),
+                                        style.to_run(7),
+                                        {
+                                            let mut style = style.clone();
+                                            let mut element = {
+                                                let mut element = div().text_color(rgba(0x2563ebff));
+                                                element.text_style().get_or_insert_with(Default::default).underline = Some(UnderlineStyle { thickness: px(1.), ..Default::default() });
+                                                element
+                                            };
+                                            if let Some(refinement) = element.text_style().clone() {
+                                                style.refine(&refinement);
+                                            }
+                                            style
+                                        }.to_run(4),
+                                        style.to_run(1),
+                                    ]),
+                                )
+                                .on_click(vec![16..20], |ix, cx| {
+                                    cx.open_url(["https://example.com"][ix]);
+                                }),
+                            )
+                    })
+                    .child({
+                        let mut element = div().mb_4();
+                        element.text_style().get_or_insert_with(Default::default).white_space = Some(WhiteSpace::Nowrap);
+                        element.style().overflow.x = Some(Overflow::Hidden);
+                        element = element.w(rems(12.0));
+                        element.child("A line that is cut off")
+                    })
+            })
+            .child({
+                let mut element = div();
+                element.style().display = Some(Display::None);
+                element = element.flex();
+                element.style().opacity = Some(0.5);
+                element.style().box_shadow = Some(Default::default());
+                element.child("Shown")
+            })
+            .child({
+                let mut element = div().gap_4();
+                let column_gap = element.style().gap.width;
+                element
+                    .flex()
+                    .flex_col()
+                    .child(
+                        grid_row(column_gap, true)
+                            .child(
+                                grid_cell(2, column_gap)
+                                    .child(div().h(px(40.0)).child("Wide")),
+                            )
+                            .child(grid_cell(1, column_gap).child(div().child("Narrow"))),
+                    )
+                    .child(
+                        grid_row(column_gap, true)
+                            .child(grid_cell(3, column_gap).child(div().child("Full"))),
+                    )
+            })
+            .child({
+                let mut element = div().h(px(120.0));
+                element.text_style().get_or_insert_with(Default::default).white_space = Some(WhiteSpace::Nowrap);
+                element
+                    .id("/4")
+                    .overflow_y_scroll()
+                    .child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .mb_4()
+                            .child(
+                                div()
+                                    .flex()
+                                    .flex_row()
+                                    .child(div().flex_none().w_6().child("3."))
+                                    .child(div().flex_1().child("Three")),
+                            )
+                            .child(
+                                div()
+                                    .flex()
+                                    .flex_row()
+                                    .child(div().flex_none().w_6().child("4."))
+                                    .child(div().flex_1().child("Four")),
+                            ),
+                    )
+            })
+            .child(
+                img("logo.png".to_string())
+                    .w(px(32.0))
+                    .h(px(32.0))
+                    .rounded_lg(),
+            )
+            .child(svg().path("icons/check.svg").top(px(2.0)).left(rems(0.5)))
+    };
+    element.into_any_element()
+}
+
+fn grid_row(column_gap: Option<DefiniteLength>, stretch: bool) -> Div {
+    let mut row = div().flex().flex_row().w_full();
+    row.style().gap.width = column_gap;
+    if stretch {
+        row = row.flex_1();
+    }
+    row
+}
+
+// Every row shares its width out in columns, one unit of flex-grow each. A cell spanning
+// several columns also covers the gaps between them, which it starts out with as its basis.
+fn grid_cell(span: usize, column_gap: Option<DefiniteLength>) -> Div {
+    let gaps = (span - 1) as f32;
+    let basis = match column_gap {
+        Some(DefiniteLength::Absolute(AbsoluteLength::Pixels(gap))) => {
+            px(f32::from(gap) * gaps).into()
+        }
+        Some(DefiniteLength::Absolute(AbsoluteLength::Rems(gap))) => rems(gap.0 * gaps).into(),
+        Some(DefiniteLength::Fraction(gap)) => relative(gap * gaps),
+        None => relative(0.),
+    };
+    let mut cell = div().flex().flex_col().flex_basis(basis).min_w_0();
+    cell.style().flex_grow = Some(span as f32);
+    cell
+}
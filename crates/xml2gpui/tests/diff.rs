@@ -0,0 +1,203 @@
+use xml2gpui::diff::{diff, Change};
+use xml2gpui::tree::try_parse_xml;
+
+fn changes(old: &str, new: &str) -> Vec<Change> {
+    diff(&try_parse_xml(old).unwrap(), &try_parse_xml(new).unwrap())
+}
+
+#[test]
+fn equal_trees_have_no_changes() {
+    let xml = r#"<div class="p-2"><p id="a">Hello <b>world</b></p><hr/></div>"#;
+    assert_eq!(changes(xml, xml), []);
+    // Comments and formatting are not part of the tree
+    assert_eq!(
+        changes(xml, "<div class=\"p-2\">\n  <!-- greeting -->\n  <p id=\"a\">Hello <b>world</b></p>\n  <hr/>\n</div>"),
+        []
+    );
+}
+
+#[test]
+fn insertion_by_position() {
+    assert_eq!(
+        changes("<div><p/></div>", "<div><p/><span/></div>"),
+        [Change::Inserted { path: vec![1] }]
+    );
+}
+
+#[test]
+fn removal_by_position() {
+    assert_eq!(
+        changes("<div><p/><span/></div>", "<div><p/></div>"),
+        [Change::Removed { path: vec![1] }]
+    );
+}
+
+#[test]
+fn changed_element_at_a_position_is_replaced() {
+    assert_eq!(
+        changes("<div><p/></div>", "<div><span/></div>"),
+        [
+            Change::Removed { path: vec![0] },
+            Change::Inserted { path: vec![0] }
+        ]
+    );
+    assert_eq!(
+        changes("<div/>", "<p/>"),
+        [
+            Change::Removed { path: vec![] },
+            Change::Inserted { path: vec![] }
+        ]
+    );
+}
+
+#[test]
+fn insertion_before_nodes_with_id_shifts_them_without_moves() {
+    assert_eq!(
+        changes(
+            r#"<div><p id="a"/><p id="b"/></div>"#,
+            r#"<div><p id="new"/><p id="a"/><p id="b"/></div>"#
+        ),
+        [Change::Inserted { path: vec![0] }]
+    );
+}
+
+#[test]
+fn removal_by_id() {
+    assert_eq!(
+        changes(
+            r#"<div><p id="a"/><p id="b"/><p id="c"/></div>"#,
+            r#"<div><p id="a"/><p id="c"/></div>"#
+        ),
+        [Change::Removed { path: vec![1] }]
+    );
+}
+
+#[test]
+fn swapped_nodes_are_one_move() {
+    assert_eq!(
+        changes(
+            r#"<div><p id="a"/><p id="b"/></div>"#,
+            r#"<div><p id="b"/><p id="a"/></div>"#
+        ),
+        [Change::Moved {
+            from: vec![0],
+            to: vec![1]
+        }]
+    );
+}
+
+#[test]
+fn moves_keep_the_longest_ordered_run_in_place() {
+    // b, c and d keep their order, only a moved to the end
+    assert_eq!(
+        changes(
+            r#"<div><p id="a"/><p id="b"/><p id="c"/><p id="d"/></div>"#,
+            r#"<div><p id="b"/><p id="c"/><p id="d"/><p id="a"/></div>"#
+        ),
+        [Change::Moved {
+            from: vec![0],
+            to: vec![3]
+        }]
+    );
+    // Reversing keeps only one node in place
+    let reversed = changes(
+        r#"<div><p id="a"/><p id="b"/><p id="c"/></div>"#,
+        r#"<div><p id="c"/><p id="b"/><p id="a"/></div>"#,
+    );
+    assert_eq!(reversed.len(), 2, "{:?}", reversed);
+    assert!(reversed
+        .iter()
+        .all(|change| matches!(change, Change::Moved { .. })));
+}
+
+#[test]
+fn moved_nodes_are_compared_too() {
+    assert_eq!(
+        changes(
+            r#"<div><p id="a">one</p><p id="b"/></div>"#,
+            r#"<div><p id="b"/><p id="a">two</p></div>"#
+        ),
+        [
+            Change::Moved {
+                from: vec![0],
+                to: vec![1]
+            },
+            Change::TextChanged {
+                path: vec![1],
+                old: Some("one".to_string()),
+                new: Some("two".to_string())
+            }
+        ]
+    );
+}
+
+#[test]
+fn nodes_with_and_without_id_do_not_match() {
+    assert_eq!(
+        changes(r#"<div><p/></div>"#, r#"<div><p id="a"/></div>"#),
+        [
+            Change::Removed { path: vec![0] },
+            Change::Inserted { path: vec![0] }
+        ]
+    );
+}
+
+#[test]
+fn attribute_changes() {
+    assert_eq!(
+        changes(
+            r#"<div class="p-2" id="root" title="x"/>"#,
+            r#"<div class="p-4" id="root" hidden="true"/>"#
+        ),
+        [
+            Change::AttributeChanged {
+                path: vec![],
+                name: "class".to_string(),
+                old: Some("p-2".to_string()),
+                new: Some("p-4".to_string())
+            },
+            Change::AttributeChanged {
+                path: vec![],
+                name: "title".to_string(),
+                old: Some("x".to_string()),
+                new: None
+            },
+            Change::AttributeChanged {
+                path: vec![],
+                name: "hidden".to_string(),
+                old: None,
+                new: Some("true".to_string())
+            },
+        ]
+    );
+}
+
+#[test]
+fn text_changes_in_nested_nodes() {
+    assert_eq!(
+        changes(
+            "<div><ul><li>one</li><li>two</li></ul></div>",
+            "<div><ul><li>one</li><li/></ul></div>"
+        ),
+        [Change::TextChanged {
+            path: vec![0, 1],
+            old: Some("two".to_string()),
+            new: None
+        }]
+    );
+}
+
+#[test]
+fn removed_paths_refer_to_the_old_tree() {
+    assert_eq!(
+        changes(
+            r#"<div><p id="a"/><p id="b"/><p id="c"/></div>"#,
+            r#"<div><p id="new"/><p id="c"/></div>"#
+        ),
+        [
+            Change::Removed { path: vec![0] },
+            Change::Removed { path: vec![1] },
+            Change::Inserted { path: vec![0] }
+        ]
+    );
+}
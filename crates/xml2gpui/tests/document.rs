@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+
+use xml2gpui::document::{load_document, Document, EmbeddedLoader};
+use xml2gpui::tree::{try_parse_xml, Component, ParseError};
+
+fn load(loader: &EmbeddedLoader, path: &str) -> Result<Document, ParseError> {
+    load_document(Path::new(path), loader)
+}
+
+fn xml(xml: &str) -> Component {
+    try_parse_xml(xml).unwrap()
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn includes_are_resolved_relative_to_the_including_file() {
+    let loader = EmbeddedLoader::new()
+        .with_file(
+            "ui/main.xml",
+            r#"<div><include src="parts/header.xml"/></div>"#,
+        )
+        .with_file(
+            "ui/parts/header.xml",
+            r#"<div class="header"><include src="../shared/logo.xml"/></div>"#,
+        )
+        .with_file("ui/shared/logo.xml", r#"<img src="logo.svg"/>"#);
+    let document = load(&loader, "ui/main.xml").unwrap();
+    assert_eq!(
+        document.root,
+        xml(r#"<div><div class="header"><img src="logo.svg"/></div></div>"#)
+    );
+    assert_eq!(
+        document.files,
+        paths(&["ui/main.xml", "ui/parts/header.xml", "ui/shared/logo.xml"])
+    );
+}
+
+#[test]
+fn fragments_contribute_their_children() {
+    let loader = EmbeddedLoader::new()
+        .with_file(
+            "main.xml",
+            r#"<div><p/><include src="items.xml"/><hr/></div>"#,
+        )
+        .with_file(
+            "items.xml",
+            "<fragment><span>a</span><span>b</span></fragment>",
+        );
+    let document = load(&loader, "main.xml").unwrap();
+    assert_eq!(
+        document.root,
+        xml("<div><p/><span>a</span><span>b</span><hr/></div>")
+    );
+}
+
+#[test]
+fn files_are_listed_once() {
+    let loader = EmbeddedLoader::new()
+        .with_file(
+            "main.xml",
+            r#"<div><include src="./row.xml"/><include src="row.xml"/></div>"#,
+        )
+        .with_file("row.xml", "<p>row</p>");
+    let document = load(&loader, "./main.xml").unwrap();
+    assert_eq!(document.root, xml("<div><p>row</p><p>row</p></div>"));
+    assert_eq!(document.files, paths(&["main.xml", "row.xml"]));
+}
+
+#[test]
+fn include_as_root_stands_for_the_included_file() {
+    let loader = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<include src="views/panel.xml"/>"#)
+        .with_file(
+            "views/panel.xml",
+            r#"<div class="panel"><include src="row.xml"/></div>"#,
+        )
+        .with_file("views/row.xml", "<p>row</p>");
+    let document = load(&loader, "main.xml").unwrap();
+    assert_eq!(document.root, xml(r#"<div class="panel"><p>row</p></div>"#));
+    assert_eq!(
+        document.files,
+        paths(&["main.xml", "views/panel.xml", "views/row.xml"])
+    );
+}
+
+#[test]
+fn fragment_as_root_becomes_a_div() {
+    let loader = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<include src="items.xml"/>"#)
+        .with_file("items.xml", "<fragment><p>a</p><p>b</p></fragment>");
+    let document = load(&loader, "main.xml").unwrap();
+    assert_eq!(document.root, xml("<div><p>a</p><p>b</p></div>"));
+}
+
+#[test]
+fn include_cycles_are_errors() {
+    let loader = EmbeddedLoader::new()
+        .with_file("a.xml", r#"<div><include src="b.xml"/></div>"#)
+        .with_file("b.xml", r#"<div><include src="./a.xml"/></div>"#);
+    let error = load(&loader, "a.xml").unwrap_err();
+    assert_eq!(error.message, "Include cycle: a.xml -> b.xml -> a.xml");
+    assert_eq!(error.file, Some(PathBuf::from("b.xml")));
+
+    let loader = EmbeddedLoader::new().with_file("self.xml", r#"<include src="self.xml"/>"#);
+    let error = load(&loader, "self.xml").unwrap_err();
+    assert_eq!(error.message, "Include cycle: self.xml -> self.xml");
+}
+
+#[test]
+fn errors_name_the_file_they_are_in() {
+    let loader = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<div><include src="broken.xml"/></div>"#)
+        .with_file("broken.xml", "<div>\n  <p></div>");
+    let error = load(&loader, "main.xml").unwrap_err();
+    assert_eq!(error.file, Some(PathBuf::from("broken.xml")));
+    assert_eq!(error.line, 2);
+
+    let loader =
+        EmbeddedLoader::new().with_file("main.xml", r#"<div><include src="gone.xml"/></div>"#);
+    let error = load(&loader, "main.xml").unwrap_err();
+    assert_eq!(error.file, Some(PathBuf::from("gone.xml")));
+
+    let loader = EmbeddedLoader::new().with_file("main.xml", "<div><include/></div>");
+    let error = load(&loader, "main.xml").unwrap_err();
+    assert_eq!(error.message, "include element must have src attribute");
+    assert_eq!(error.file, Some(PathBuf::from("main.xml")));
+}
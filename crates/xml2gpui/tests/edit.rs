@@ -0,0 +1,97 @@
+use xml2gpui::tree::{try_parse_xml, Component};
+
+fn parse(xml: &str) -> Component {
+    try_parse_xml(xml).unwrap()
+}
+
+#[test]
+fn inserting_into_an_element_with_text_makes_the_text_a_child() {
+    let mut root = parse("<p>Hello</p>");
+    root.insert_child(0, parse("<b>Bold</b>"));
+    assert_eq!(root.text, None);
+    assert_eq!(root.children.len(), 2);
+    assert_eq!(root.children[0].elem, "b");
+    assert!(root.children[1].is_text_node());
+    assert_eq!(root.children[1].text.as_deref(), Some("Hello"));
+    assert_eq!(root.to_xml(), "<p><b>Bold</b>Hello</p>");
+
+    let mut root = parse("<p>Hello</p>");
+    root.insert_child(1, parse("<b>Bold</b>"));
+    assert_eq!(root.to_xml(), "<p>Hello<b>Bold</b></p>");
+}
+
+#[test]
+fn pushed_children_go_after_the_text() {
+    let mut root = parse("<p>Hello</p>");
+    root.push_child(parse("<b>Bold</b>"));
+    root.push_child(Component::text_node("!"));
+    assert_eq!(root.text, None);
+    assert_eq!(root.to_xml(), "<p>Hello<b>Bold</b>!</p>");
+
+    let mut root = parse("<div/>");
+    root.push_child(parse("<span/>"));
+    assert_eq!(root.children.len(), 1);
+}
+
+#[test]
+fn insert_at_checks_the_path() {
+    let mut root = parse("<div><ul><li>a</li></ul><p>text</p></div>");
+    let before = root.clone();
+    assert!(!root.insert_at(&[], parse("<li/>")));
+    assert!(!root.insert_at(&[2, 0], parse("<li/>")));
+    assert!(!root.insert_at(&[0, 2], parse("<li>c</li>")));
+    // Text on its own counts as a child
+    assert!(!root.insert_at(&[1, 2], parse("<b/>")));
+    assert_eq!(root, before);
+
+    assert!(root.insert_at(&[0, 1], parse("<li>b</li>")));
+    assert!(root.insert_at(&[1, 1], parse("<b>bold</b>")));
+    assert!(root.insert_at(&[2], parse("<hr/>")));
+    assert_eq!(
+        root.to_xml(),
+        "<div>\n  <ul>\n    <li>a</li>\n    <li>b</li>\n  </ul>\n  <p>text<b>bold</b></p>\n  <hr/>\n</div>"
+    );
+}
+
+#[test]
+fn nodes_are_found_and_removed_by_id_and_path() {
+    let mut root = parse(r#"<div><ul id="list"><li id="a"/><li id="b"/></ul></div>"#);
+    assert_eq!(root.path_of_id("b"), Some(vec![0, 1]));
+    assert_eq!(root.find_by_id("list").unwrap().children.len(), 2);
+    root.find_by_id_mut("a").unwrap().set_text("first");
+    assert_eq!(
+        root.at_path(&[0, 0]).unwrap().text.as_deref(),
+        Some("first")
+    );
+
+    assert_eq!(root.remove_by_id("a").unwrap().attribute("id"), Some("a"));
+    assert_eq!(root.path_of_id("b"), Some(vec![0, 0]));
+    assert!(root.remove_at(&[]).is_none());
+    assert!(root.remove_at(&[0, 1]).is_none());
+    assert!(root.remove_by_id("a").is_none());
+}
+
+#[test]
+fn attributes_and_classes() {
+    let mut root = parse(r#"<div class="flex" id="x"/>"#);
+    root.set_attribute("id", "y");
+    root.set_attribute("title", "t");
+    assert_eq!(
+        root.attributes,
+        [
+            ("class".to_string(), "flex".to_string()),
+            ("id".to_string(), "y".to_string()),
+            ("title".to_string(), "t".to_string())
+        ]
+    );
+    assert_eq!(root.remove_attribute("title").as_deref(), Some("t"));
+    assert_eq!(root.remove_attribute("title"), None);
+
+    root.add_class("p-4");
+    root.add_class("flex");
+    assert_eq!(root.attribute("class"), Some("flex p-4"));
+    assert!(root.remove_class("flex"));
+    assert!(!root.remove_class("flex"));
+    assert!(root.remove_class("p-4"));
+    assert_eq!(root.attribute("class"), None);
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "render")]
+
+use xml2gpui::form::{FormState, Value};
+
+#[test]
+fn text_values_are_false_when_empty_zero_or_false() {
+    for text in ["", "0", "false", "FALSE", " False ", "  "] {
+        assert!(!Value::Text(text.to_string()).as_bool(), "{:?}", text);
+    }
+    for text in ["1", "true", "yes", "on", "checked", "00"] {
+        assert!(Value::Text(text.to_string()).as_bool(), "{:?}", text);
+    }
+}
+
+#[test]
+fn bool_values_keep_their_value() {
+    assert!(Value::Bool(true).as_bool());
+    assert!(!Value::Bool(false).as_bool());
+}
+
+#[test]
+fn values_convert_to_text() {
+    assert_eq!(Value::Bool(true).as_text(), "true");
+    assert_eq!(Value::Bool(false).as_text(), "false");
+    assert_eq!(Value::Text("hello".to_string()).as_text(), "hello");
+}
+
+#[test]
+fn form_state_stores_values_by_name() {
+    let mut form = FormState::new();
+    assert_eq!(form.value("agree"), None);
+
+    form.set_value("agree", Value::Bool(true));
+    form.set_value("name", Value::Text("Ada".to_string()));
+    assert_eq!(form.value("agree"), Some(&Value::Bool(true)));
+    assert_eq!(form.value("name"), Some(&Value::Text("Ada".to_string())));
+
+    form.set_value("agree", Value::Text("0".to_string()));
+    assert!(!form.value("agree").unwrap().as_bool());
+}
@@ -0,0 +1,80 @@
+use xml2gpui::format::format_xml;
+use xml2gpui::tree::{try_parse_xml, try_parse_xml_with_comments};
+
+// Formats and checks that formatting again changes nothing
+fn formatted(xml: &str) -> String {
+    let formatted = format_xml(xml).unwrap();
+    assert_eq!(format_xml(&formatted).unwrap(), formatted);
+    formatted
+}
+
+#[test]
+fn nodes_around_the_root_are_kept() {
+    let xml = "<?xml version=\"1.0\"?>\n<!DOCTYPE div>\n<!-- header -->\n<div><p>a</p></div>\n<!-- footer --><?done?>\n";
+    assert_eq!(
+        formatted(xml),
+        "<?xml version=\"1.0\"?>\n<!DOCTYPE div>\n<!-- header -->\n<div>\n  <p>a</p>\n</div>\n<!-- footer -->\n<?done?>\n"
+    );
+}
+
+#[test]
+fn comments_inside_the_root_are_kept() {
+    assert_eq!(
+        formatted("<div><!-- a --><p>b</p></div>"),
+        "<div>\n  <!-- a -->\n  <p>b</p>\n</div>\n"
+    );
+}
+
+#[test]
+fn cdata_becomes_escaped_text() {
+    let xml = "<div><code><![CDATA[a < b && c]]></code></div>";
+    let formatted = formatted(xml);
+    assert_eq!(formatted, "<div><code>a &lt; b &amp;&amp; c</code></div>\n");
+    assert_eq!(
+        try_parse_xml(&formatted).unwrap(),
+        try_parse_xml(xml).unwrap()
+    );
+    assert_eq!(
+        try_parse_xml(xml).unwrap().children[0].text.as_deref(),
+        Some("a < b && c")
+    );
+}
+
+#[test]
+fn formatting_keeps_the_tree() {
+    // Already in canonical attribute and class order, which formatting doesn't change
+    let xml = r#"<div   id="x" class="flex p-4"><h1>Title</h1>Some <b>bold</b> text<br/><ul><li>a</li></ul></div>"#;
+    assert_eq!(
+        try_parse_xml(&formatted(xml)).unwrap(),
+        try_parse_xml(xml).unwrap()
+    );
+}
+
+#[test]
+fn what_can_not_be_written_back_is_an_error() {
+    let error = format_xml("<div><?pi?></div>").unwrap_err();
+    assert_eq!(
+        error.message,
+        "Processing instructions are only kept outside the root element"
+    );
+    assert!(try_parse_xml_with_comments("<div><?pi?></div>").is_err());
+    // Rendering doesn't need them
+    assert!(try_parse_xml("<div><?pi?></div>").is_ok());
+
+    let error = format_xml("text <div/>").unwrap_err();
+    assert_eq!(error.message, "Text before the root element");
+}
+
+#[test]
+fn comments_leave_the_whitespace_of_text_alone() {
+    let xml = "<div><p>a\tb\n  c<!-- note --> d</p><p><![CDATA[]]></p></div>";
+    let formatted = formatted(xml);
+    assert_eq!(
+        formatted,
+        "<div>\n  <p>a\tb\n  c<!-- note --> d</p>\n  <p/>\n</div>\n"
+    );
+    assert_eq!(
+        try_parse_xml(&formatted).unwrap(),
+        try_parse_xml(xml).unwrap()
+    );
+}
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::lint::{check_classes, lint, Severity};
+use xml2gpui::text::build_paragraph;
+use xml2gpui::theme::Theme;
+use xml2gpui::tree::{try_parse_xml, Location};
+
+#[test]
+fn known_classes_pass() {
+    assert_eq!(
+        check_classes("flex flex-col w-[10px] bg-[#ff0000] shadow-md text-2xl"),
+        []
+    );
+}
+
+#[test]
+fn unrecognized_and_invalid_classes_are_errors() {
+    assert_eq!(
+        check_classes("flex flex-column bg-[#nothex]"),
+        [
+            (
+                Severity::Error,
+                "Unrecognized class: flex-column".to_string()
+            ),
+            (Severity::Error, "Invalid value: bg-[#nothex]".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn unsupported_classes_are_warnings() {
+    let messages = check_classes("shadow-inner z-10");
+    assert_eq!(messages.len(), 2, "{:?}", messages);
+    assert!(messages
+        .iter()
+        .all(|(severity, _)| *severity == Severity::Warning));
+}
+
+#[test]
+fn conflicting_classes_are_reported_once_per_group() {
+    assert_eq!(
+        check_classes("flex-row p-2 flex-col flex-row"),
+        [(
+            Severity::Warning,
+            "Conflicting classes: flex-row flex-col".to_string()
+        )]
+    );
+    // Repeating a class is harmless
+    assert_eq!(check_classes("flex flex"), []);
+}
+
+#[test]
+fn template_props_in_classes_are_skipped() {
+    assert_eq!(check_classes("p-2 {{size}} bg-{{color}}"), []);
+}
+
+#[test]
+fn lint_reports_elements_with_their_location() {
+    let root = try_parse_xml("<div>\n  <blink/>\n  <img class=\"w-4\"/>\n</div>").unwrap();
+    let messages = lint(&root, &HashSet::new());
+    let summary: Vec<(Severity, &str, Option<Location>)> = messages
+        .iter()
+        .map(|m| (m.severity, m.message.as_str(), m.location))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (
+                Severity::Error,
+                "Unknown element <blink>",
+                Some(Location { line: 2, column: 3 })
+            ),
+            (
+                Severity::Error,
+                "<img> must have src attribute",
+                Some(Location { line: 3, column: 3 })
+            ),
+        ]
+    );
+}
+
+#[test]
+fn templates_are_known_elements() {
+    let root = try_parse_xml("<div><card/></div>").unwrap();
+    let templates = HashSet::from(["card".to_string()]);
+    assert_eq!(lint(&root, &templates), []);
+}
+
+#[test]
+fn classes_on_inline_elements_are_checked_like_the_renderer_resolves_them() {
+    let root = try_parse_xml(
+        r#"<p>Some <span class="text-lg font-mono p-4">big</span> <b class="italic flex-column">text</b></p>"#,
+    )
+    .unwrap();
+    let messages: Vec<String> = lint(&root, &HashSet::new())
+        .into_iter()
+        .map(|m| m.message)
+        .collect();
+    assert_eq!(messages, ["Unrecognized class: flex-column"]);
+
+    let (_, diagnostics) =
+        collect_diagnostics(|| build_paragraph(&root.children, &Theme::default()));
+    let diagnostics: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+    assert_eq!(diagnostics, messages);
+}
@@ -0,0 +1,69 @@
+use xml2gpui::tree::{line_column, try_parse_xml, ParseError};
+
+fn parse_error(xml: &str) -> ParseError {
+    try_parse_xml(xml).expect_err(xml)
+}
+
+#[test]
+fn mismatched_end_tag_points_at_the_tag() {
+    let error = parse_error("<div>\n  <p>text</span>\n</div>");
+    assert_eq!((error.line, error.column), (2, 10), "{}", error);
+}
+
+#[test]
+fn element_after_the_root_points_at_it() {
+    let error = parse_error("<div></div>\n<p/>");
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.message, "<p> after the root element");
+}
+
+#[test]
+fn invalid_attribute_name_points_at_its_element() {
+    let error = parse_error("<div>\n    <span a\"b=\"c\"/>\n</div>");
+    assert_eq!((error.line, error.column), (2, 5), "{}", error);
+}
+
+#[test]
+fn unclosed_element_points_at_the_end() {
+    let error = parse_error("<div>\n  <p>\n</div>");
+    assert_eq!(error.line, 3, "{}", error);
+    let error = parse_error("<div>\n  text");
+    assert_eq!((error.line, error.column), (2, 7));
+    assert_eq!(error.message, "Unclosed element <div>");
+}
+
+#[test]
+fn empty_document_has_no_root() {
+    let error = parse_error("");
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(error.message, "Document has no root element");
+}
+
+#[test]
+fn columns_count_characters() {
+    let error = parse_error("<div>äöü</div>\n<p/>");
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(line_column("äöü<", 6), (1, 4));
+    // Offsets inside a character round down to its start
+    assert_eq!(line_column("äöü<", 5), (1, 3));
+    assert_eq!(line_column("a\nb", 100), (2, 2));
+}
+
+#[test]
+fn display_includes_file_and_position() {
+    let error = parse_error("<div>\n</p>").with_file("ui/main.xml");
+    assert!(
+        error.to_string().starts_with("ui/main.xml:2:1:"),
+        "{}",
+        error
+    );
+    assert_eq!(ParseError::new("Cannot read").to_string(), "Cannot read");
+}
+
+#[test]
+#[allow(deprecated)]
+fn parse_xml_shows_errors_instead_of_panicking() {
+    let root = xml2gpui::tree::parse_xml("<div>".to_string());
+    assert_eq!(root.elem, "div");
+    assert!(root.text.unwrap().starts_with("Error: "));
+}
@@ -0,0 +1,172 @@
+// Properties over arbitrary markup and classes, the same ones the fuzz targets in fuzz/ check
+// with coverage guidance
+#[cfg(feature = "render")]
+use gpui::div;
+use proptest::prelude::*;
+
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::format::{format_xml, sort_classes};
+use xml2gpui::lint::check_classes;
+use xml2gpui::style::ResolvedStyle;
+use xml2gpui::tree::{try_parse_xml, try_parse_xml_with_comments, Component};
+
+// Inline and block elements, so that generated markup mixes text and elements both ways
+const ELEMENTS: &[&str] = &["div", "p", "li", "span", "b", "a", "br", "img", "card"];
+const ATTRIBUTES: &[&str] = &["id", "class", "src", "font", "data-x"];
+
+// Characters that need escaping, whitespace runs and multi-byte characters
+fn text() -> impl Strategy<Value = String> {
+    "[a-z0-9 \t\n&<>\"'é漢🦀]{0,12}"
+}
+
+fn class_list() -> impl Strategy<Value = String> {
+    let class_name = prop_oneof![
+        "(bg|text-color|border|from|shadow-color)-\\[#[0-9a-fA-Fé漢]{0,9}\\]?",
+        "(rounded|border|w|h|top|left)-(t-|bl-)?\\[?-?[0-9.]{0,4}(px|rem|é)?\\]?",
+        "shadow-\\[[0-9a-f#_,.pxrem-]{0,16}\\]",
+        "(opacity|leading|order|z|grid-cols|col-span)-[0-9a-z.é]{0,4}",
+        "[a-z0-9\\[\\]#/.é-]{1,12}",
+    ];
+    prop::collection::vec(class_name, 0..6).prop_map(|classes| classes.join(" "))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn attributes() -> impl Strategy<Value = String> {
+    let attribute = (prop::sample::select(ATTRIBUTES), text());
+    prop::collection::vec(attribute, 0..3).prop_map(|attributes| {
+        let mut seen = Vec::new();
+        let mut markup = String::new();
+        for (name, value) in attributes {
+            // Duplicate attributes are a parse error
+            if !seen.contains(&name) {
+                seen.push(name);
+                markup.push_str(&format!(" {}=\"{}\"", name, escape(&value)));
+            }
+        }
+        markup
+    })
+}
+
+fn comment() -> impl Strategy<Value = String> {
+    "[a-z ]{0,6}".prop_map(|comment| format!("<!--{}-->", comment))
+}
+
+// Well-formed markup with a div at the root, and comments in between and around it
+fn markup() -> impl Strategy<Value = String> {
+    let leaf = prop_oneof![
+        text().prop_map(|text| escape(&text)),
+        comment(),
+        text().prop_map(|text| format!("<![CDATA[{}]]>", text)),
+        (prop::sample::select(ELEMENTS), attributes())
+            .prop_map(|(elem, attributes)| format!("<{}{}/>", elem, attributes)),
+    ];
+    let content = leaf.prop_recursive(4, 48, 4, |inner| {
+        (
+            prop::sample::select(ELEMENTS),
+            attributes(),
+            prop::collection::vec(inner, 0..4),
+        )
+            .prop_map(|(elem, attributes, children)| {
+                format!("<{}{}>{}</{}>", elem, attributes, children.concat(), elem)
+            })
+    });
+    (
+        prop::collection::vec(comment(), 0..2),
+        prop::collection::vec(content, 0..4),
+        prop::collection::vec(comment(), 0..2),
+    )
+        .prop_map(|(before, children, after)| {
+            format!(
+                "{}<div>{}</div>{}",
+                before.join("\n"),
+                children.concat(),
+                after.join("\n")
+            )
+        })
+}
+
+// The printer orders attributes and sorts classes, see format.rs. Everything else has to
+// match exactly.
+fn normalized(mut component: Component) -> Component {
+    component.attributes.sort();
+    for (name, value) in &mut component.attributes {
+        if name == "class" {
+            *value = sort_classes(value);
+        }
+    }
+    component.children = component.children.into_iter().map(normalized).collect();
+    component
+}
+
+// What the parse fuzz target checks: the input parses or fails with an error, and whatever
+// parses prints and formats the same way again
+fn check_printing(xml: &str) {
+    if let Ok(root) = try_parse_xml(xml) {
+        let printed = root.to_xml();
+        let reparsed = try_parse_xml(&printed).expect("printed markup parses");
+        assert_eq!(reparsed.to_xml(), printed);
+    }
+    if try_parse_xml_with_comments(xml).is_ok() {
+        let formatted = format_xml(xml).expect("markup that parses formats");
+        assert_eq!(format_xml(&formatted).unwrap(), formatted);
+    }
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_input_prints_stably(xml in any::<String>()) {
+        check_printing(&xml);
+    }
+
+    #[test]
+    fn markup_like_input_prints_stably(
+        xml in "[<>/a-z =\"'&;:!?#é漢\\[\\]-]{0,40}|<(div|span)( a=\"[a-z<&;]{0,3}\")?>([a-z &;<>!-]|<b>|</b>|<!--|-->|<\\?x\\?>|<!\\[CDATA\\[|\\]\\]>){0,12}</(div|span)>[a-z <>/!-]{0,6}"
+    ) {
+        check_printing(&xml);
+    }
+
+    #[test]
+    fn printed_trees_parse_back_the_same(xml in markup()) {
+        let root = try_parse_xml(&xml).unwrap();
+        let printed = root.to_xml();
+        let reparsed = try_parse_xml(&printed).unwrap();
+        prop_assert_eq!(reparsed.to_xml(), printed.clone());
+        prop_assert_eq!(normalized(reparsed), normalized(root), "{}", printed);
+    }
+
+    // Once printed, trees print in canonical order and parse back exactly
+    #[test]
+    fn printed_trees_parse_back_exactly(xml in markup()) {
+        let root = try_parse_xml(&try_parse_xml(&xml).unwrap().to_xml()).unwrap();
+        let printed = root.to_xml();
+        prop_assert_eq!(try_parse_xml(&printed).unwrap(), root, "{}", printed);
+    }
+
+    #[test]
+    fn formatting_is_stable(xml in markup()) {
+        let formatted = format_xml(&xml).unwrap();
+        prop_assert_eq!(format_xml(&formatted).unwrap(), formatted.clone());
+        prop_assert_eq!(
+            normalized(try_parse_xml(&formatted).unwrap()),
+            normalized(try_parse_xml(&xml).unwrap())
+        );
+    }
+
+    #[test]
+    fn resolving_classes_never_panics(classes in prop_oneof![any::<String>(), class_list()]) {
+        let (_style, _) = collect_diagnostics(|| {
+            check_classes(&classes);
+            ResolvedStyle::from_classes(&classes)
+        });
+        #[cfg(feature = "render")]
+        _style.apply(div());
+        let sorted = sort_classes(&classes);
+        prop_assert_eq!(sort_classes(&sorted), sorted);
+    }
+}
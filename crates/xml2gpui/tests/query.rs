@@ -0,0 +1,88 @@
+use xml2gpui::query::{Selector, SelectorError};
+use xml2gpui::tree::{try_parse_xml, Component};
+
+const XML: &str = r##"<div id="app" class="flex">
+  <ul id="list">
+    <li class="row first" data-kind="a">One</li>
+    <li class="row"><span class="row">Two</span></li>
+  </ul>
+  <div class="bg-[#ffffff] row" data-kind="b c">Three</div>
+</div>"##;
+
+fn root() -> Component {
+    try_parse_xml(XML).unwrap()
+}
+
+// Text of each match
+fn texts(root: &Component, selector: &str) -> Vec<String> {
+    root.query_all(selector)
+        .unwrap()
+        .iter()
+        .map(|component| {
+            let text = component.text.clone();
+            text.or_else(|| component.children.iter().find_map(|c| c.text.clone()))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+#[test]
+fn compound_selectors() {
+    let root = root();
+    assert_eq!(texts(&root, "li"), ["One", "Two"]);
+    assert_eq!(texts(&root, "li.row.first"), ["One"]);
+    assert_eq!(texts(&root, "[data-kind]"), ["One", "Three"]);
+    assert_eq!(texts(&root, "[data-kind='b c']"), ["Three"]);
+    assert_eq!(texts(&root, "*[data-kind=a]"), ["One"]);
+    assert_eq!(root.query("#app").unwrap().unwrap().elem, "div");
+    assert!(root.query("#missing").unwrap().is_none());
+}
+
+#[test]
+fn combinators() {
+    let root = root();
+    assert_eq!(texts(&root, "#app .row"), ["One", "Two", "Two", "Three"]);
+    assert_eq!(texts(&root, "#app > .row"), ["Three"]);
+    assert_eq!(texts(&root, "ul>li  >span"), ["Two"]);
+    assert_eq!(texts(&root, "#list .row"), ["One", "Two", "Two"]);
+    assert!(texts(&root, "#list > span").is_empty());
+    assert_eq!(
+        root.query_paths("#app > ul li").unwrap(),
+        [vec![0, 0], vec![0, 1]]
+    );
+}
+
+#[test]
+fn escapes() {
+    let root = root();
+    assert_eq!(texts(&root, r".bg-\[\#ffffff\]"), ["Three"]);
+    assert_eq!(texts(&root, r#"[data-kind="b\ c"]"#), ["Three"]);
+    assert_eq!(texts(&root, r#"[data-kind="\"x"]"#), Vec::<String>::new());
+}
+
+#[test]
+fn invalid_selectors_are_errors() {
+    let error = |selector: &str| Selector::parse(selector).unwrap_err();
+    let expected = |message: &str, position| SelectorError {
+        message: message.to_string(),
+        position,
+    };
+    assert_eq!(
+        error(""),
+        expected("Expected a tag, id, class or attribute", 0)
+    );
+    assert_eq!(
+        error("div >"),
+        expected("Expected a tag, id, class or attribute", 5)
+    );
+    assert_eq!(error("div#"), expected("Expected a name", 4));
+    assert_eq!(error("[id"), expected("Missing ]", 3));
+    assert_eq!(error("[id=\"x]"), expected("Unterminated string", 5));
+    assert_eq!(error(".a\\"), expected("Escape at the end", 1));
+    assert_eq!(error("div,span"), expected("Unexpected ','", 3));
+
+    let root = root();
+    assert_eq!(root.query("li[").unwrap_err().position, 3);
+    assert!(root.query_all("li[").is_err());
+    assert!(root.query_paths("li[").is_err());
+}
@@ -0,0 +1,135 @@
+// Run with `cargo test -p xml2gpui --features test-support`
+#![cfg(feature = "test-support")]
+
+use std::panic::catch_unwind;
+
+use gpui::TestAppContext;
+use xml2gpui::diagnostics::{collect_diagnostics, Diagnostic};
+use xml2gpui::snapshot::{assert_snapshot, snapshot};
+
+#[gpui::test]
+fn fixed_sizes_stack_in_a_column(cx: &mut TestAppContext) {
+    let snapshot = snapshot(
+        cx,
+        r#"<div class="flex flex-col"><div id="a" class="w-32 h-8"/><div id="b" class="w-16 h-4"/></div>"#,
+    );
+    let lines: Vec<&str> = snapshot.lines().collect();
+    assert_eq!(lines.len(), 3, "{}", snapshot);
+    assert!(
+        lines[0].ends_with("display=flex direction=column"),
+        "{}",
+        snapshot
+    );
+    assert_eq!(lines[1], "  div#a 0,0 128x32");
+    assert_eq!(lines[2], "  div#b 0,32 64x16");
+}
+
+#[gpui::test]
+fn templates_render_like_their_expansion(cx: &mut TestAppContext) {
+    let with_template = snapshot(
+        cx,
+        r#"<div>
+            <template name="card" props="title"><div class="w-32 p-4 bg-[#ffffff]">{{title}}</div></template>
+            <card title="Hello"/>
+        </div>"#,
+    );
+    let expanded = snapshot(
+        cx,
+        r#"<div><div class="w-32 p-4 bg-[#ffffff]">Hello</div></div>"#,
+    );
+    assert_eq!(with_template, expanded);
+    assert!(expanded.contains("background=#ffffffff"), "{}", expanded);
+}
+
+#[gpui::test]
+fn grid_columns_line_up_across_spans_and_gaps(cx: &mut TestAppContext) {
+    let snapshot = snapshot(
+        cx,
+        r#"<div class="grid grid-cols-2 gap-4 w-64"><div id="a" class="col-span-2 h-8"/><div id="b" class="h-8"/><div id="c" class="h-8"/></div>"#,
+    );
+    let lines: Vec<&str> = snapshot.lines().collect();
+    assert_eq!(
+        lines[1..],
+        [
+            "  div#a 0,0 256x32",
+            "  div#b 0,48 120x32",
+            "  div#c 136,48 120x32"
+        ],
+        "{}",
+        snapshot
+    );
+}
+
+#[gpui::test]
+fn scroll_targets_that_are_not_found_are_reported(cx: &mut TestAppContext) {
+    let (_, diagnostics) = collect_diagnostics(|| {
+        snapshot(
+            cx,
+            r#"<div>
+                <div id="outer" class="overflow-y-scroll h-8">
+                    <div id="inner" class="overflow-y-scroll h-4" scroll-to="missing"><p id="a">a</p></div>
+                    <p id="b">b</p>
+                </div>
+            </div>"#,
+        )
+    });
+    // The outer element doesn't take over the target of the inner one
+    assert_eq!(
+        diagnostics,
+        [Diagnostic::Element(
+            "scroll-to target missing not found in inner".to_string()
+        )]
+    );
+}
+
+// The snapshots are checked in under tests/snapshots, run with XML2GPUI_UPDATE_SNAPSHOTS=1 to
+// update them after an intended change
+#[gpui::test]
+fn flex_column_matches_its_snapshot(cx: &mut TestAppContext) {
+    let snapshot = snapshot(
+        cx,
+        r#"<div class="flex flex-col w-64 h-32">
+            <div id="a" class="w-32 h-8 bg-[#ff0000]"/>
+            <div id="b" class="w-16 h-4 opacity-50"/>
+        </div>"#,
+    );
+    assert_snapshot("tests/snapshots/flex_column.snap", &snapshot);
+}
+
+#[gpui::test]
+fn grid_matches_its_snapshot(cx: &mut TestAppContext) {
+    let snapshot = snapshot(
+        cx,
+        r#"<div class="grid grid-cols-2 gap-4 w-64">
+            <div id="a" class="col-span-2 h-8"/>
+            <div id="b" class="h-8"/>
+            <div id="c" class="h-8"/>
+        </div>"#,
+    );
+    assert_snapshot("tests/snapshots/grid.snap", &snapshot);
+}
+
+#[test]
+fn new_snapshots_are_written_then_compared() {
+    // Updating writes instead of comparing, which is not what this test is about
+    if std::env::var("XML2GPUI_UPDATE_SNAPSHOTS").is_ok() {
+        return;
+    }
+    let path = std::env::temp_dir()
+        .join(format!("xml2gpui-snapshot-{}", std::process::id()))
+        .join("new.snap");
+    let _ = std::fs::remove_file(&path);
+
+    assert!(catch_unwind(|| assert_snapshot(&path, "div 0,0 10x10\n")).is_err());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "div 0,0 10x10\n");
+    assert_snapshot(&path, "div 0,0 10x10\n");
+    let changed = catch_unwind(|| assert_snapshot(&path, "div 0,0 20x10\n")).unwrap_err();
+    let message = changed.downcast_ref::<String>().unwrap();
+    assert!(
+        message.contains("   1 - div 0,0 10x10\n   1 + div 0,0 20x10"),
+        "{}",
+        message
+    );
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -0,0 +1,255 @@
+use xml2gpui::classes::{OTHER_CLASSES, TAILWIND_CLASSES, VALUE_CLASSES};
+use xml2gpui::diagnostics::{collect_diagnostics, Diagnostic};
+use xml2gpui::style::{Color, Corner, Declaration, Length, LineHeight, ResolvedStyle, Shadow};
+use xml2gpui::theme::Theme;
+
+fn resolve(classes: &str) -> (Vec<Declaration>, Vec<Diagnostic>) {
+    let (style, diagnostics) = collect_diagnostics(|| ResolvedStyle::from_classes(classes));
+    (style.declarations, diagnostics)
+}
+
+#[test]
+fn every_table_class_resolves_to_itself() {
+    for (class_name, _) in TAILWIND_CLASSES {
+        let (declarations, diagnostics) = resolve(class_name);
+        assert_eq!(
+            declarations,
+            [Declaration::Class(class_name.to_string())],
+            "{}",
+            class_name
+        );
+        assert!(diagnostics.is_empty(), "{}: {:?}", class_name, diagnostics);
+    }
+}
+
+#[test]
+fn classes_listed_for_editors_are_handled() {
+    for (class_name, _) in OTHER_CLASSES {
+        let (_, diagnostics) = resolve(class_name);
+        assert_eq!(diagnostics, [], "{}", class_name);
+    }
+    // The examples in the descriptions, where they are complete classes
+    for (prefix, description) in VALUE_CLASSES {
+        let (_, examples) = description.split_once(": ").unwrap();
+        for example in examples.split(" or ").flat_map(|e| e.split(", ")) {
+            assert!(example.starts_with(prefix), "{}", description);
+            if !example.contains("rrggbb") {
+                let (_, diagnostics) = resolve(example);
+                assert_eq!(diagnostics, [], "{}", example);
+            }
+        }
+    }
+}
+
+#[test]
+fn declarations_keep_the_class_order() {
+    let (declarations, diagnostics) = resolve("w-[10px] flex inline-flex w-[1.5rem] opacity-50");
+    assert_eq!(
+        declarations,
+        [
+            Declaration::Width(Length::Pixels(10.)),
+            Declaration::Class("flex".to_string()),
+            Declaration::Class("flex".to_string()),
+            Declaration::Width(Length::Rems(1.5)),
+            Declaration::Opacity(0.5),
+        ]
+    );
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn colors() {
+    let (declarations, diagnostics) =
+        resolve("bg-[#ff0000] text-color-[#00ff0080] border-[#0000FF] from-[#123456]");
+    assert_eq!(
+        declarations,
+        [
+            Declaration::Background(Color(0xff0000ff)),
+            Declaration::TextColor(Color(0x00ff0080)),
+            Declaration::BorderColor(Color(0x0000ffff)),
+            Declaration::Background(Color(0x123456ff)),
+        ]
+    );
+    assert!(diagnostics.is_empty());
+
+    for class_name in [
+        "bg-[#fff]",
+        "bg-[#gggggg]",
+        "text-color-[#ff0000",
+        "border-[#]",
+    ] {
+        let (declarations, diagnostics) = resolve(class_name);
+        assert!(declarations.is_empty(), "{}", class_name);
+        assert_eq!(
+            diagnostics,
+            [Diagnostic::InvalidValue(class_name.to_string())]
+        );
+    }
+}
+
+#[test]
+fn rounded_corners() {
+    let (declarations, _) = resolve("rounded-8px rounded-1.5rem rounded-lg");
+    assert_eq!(
+        declarations,
+        [
+            Declaration::Rounded(Corner::All, Length::Pixels(8.)),
+            Declaration::Rounded(Corner::All, Length::Rems(1.5)),
+            Declaration::Class("rounded-lg".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn shadows_and_their_color() {
+    let style = ResolvedStyle::from_classes(
+        "shadow-[0_4px_12px_#00000040,1px_1px] shadow-md shadow-none shadow-color-[#ff000080]",
+    );
+    assert_eq!(
+        style.declarations,
+        [
+            Declaration::Shadows(vec![
+                Shadow {
+                    color: Color(0x00000040),
+                    x: 0.,
+                    y: 4.,
+                    blur: 12.,
+                    spread: 0.,
+                },
+                Shadow {
+                    color: Color(0x0000001a),
+                    x: 1.,
+                    y: 1.,
+                    blur: 0.,
+                    spread: 0.,
+                },
+            ]),
+            Declaration::Class("shadow-md".to_string()),
+            Declaration::Shadows(Vec::new()),
+        ]
+    );
+    assert_eq!(style.shadow_color, Some(Color(0xff000080)));
+
+    let (declarations, diagnostics) = resolve("shadow-[4px]");
+    assert!(declarations.is_empty());
+    assert_eq!(
+        diagnostics,
+        [Diagnostic::InvalidValue("shadow-[4px]".to_string())]
+    );
+
+    for class_name in [
+        "shadow-color-[#zzz]",
+        "shadow-color-[red]",
+        "shadow-color-[#ff0000",
+    ] {
+        let (style, diagnostics) = collect_diagnostics(|| ResolvedStyle::from_classes(class_name));
+        assert_eq!(style.shadow_color, None);
+        assert_eq!(
+            diagnostics,
+            [Diagnostic::InvalidValue(class_name.to_string())]
+        );
+    }
+}
+
+#[test]
+fn text_classes() {
+    let (declarations, diagnostics) =
+        resolve("text-4xl leading-tight leading-6 italic no-underline truncate");
+    assert_eq!(
+        declarations,
+        [
+            Declaration::FontSize(2.25),
+            Declaration::LineHeight(LineHeight::Relative(1.25)),
+            Declaration::LineHeight(LineHeight::Rems(1.5)),
+            Declaration::Italic(true),
+            Declaration::Underline(false),
+            Declaration::Truncate,
+        ]
+    );
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn line_heights_must_be_finite_and_not_negative() {
+    for class_name in ["leading-inf", "leading-NaN", "leading--4"] {
+        let (declarations, diagnostics) = resolve(class_name);
+        assert!(declarations.is_empty(), "{:?}", declarations);
+        assert_eq!(
+            diagnostics,
+            [Diagnostic::InvalidValue(class_name.to_string())]
+        );
+    }
+    let (declarations, _) = resolve("leading-0");
+    assert_eq!(
+        declarations,
+        [Declaration::LineHeight(LineHeight::Rems(0.))]
+    );
+}
+
+#[test]
+fn classes_handled_while_rendering_resolve_to_nothing() {
+    let (declarations, diagnostics) =
+        resolve("overflow-auto order-2 grid grid-cols-3 col-span-2 font-mono via-[#ffffff]");
+    assert!(declarations.is_empty(), "{:?}", declarations);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+}
+
+#[test]
+fn problems_are_reported() {
+    let (declarations, diagnostics) = resolve("flex-column z-10 text-center");
+    assert!(declarations.is_empty());
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(
+        diagnostics[0],
+        Diagnostic::UnrecognizedClass("flex-column".to_string())
+    );
+    assert!(matches!(diagnostics[1], Diagnostic::Unsupported(_)));
+    assert!(matches!(diagnostics[2], Diagnostic::Unsupported(_)));
+}
+
+#[test]
+fn gradient_stops_need_arbitrary_colors() {
+    let (declarations, diagnostics) =
+        resolve("from-[#123456] via-[#ffffff] to-[#000000] from-blue-500 to-[#xyz] via-white");
+    assert_eq!(declarations, [Declaration::Background(Color(0x123456ff))]);
+    assert_eq!(
+        diagnostics,
+        [
+            Diagnostic::UnrecognizedClass("from-blue-500".to_string()),
+            Diagnostic::InvalidValue("to-[#xyz]".to_string()),
+            Diagnostic::UnrecognizedClass("via-white".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn opacity_outside_0_to_100_is_invalid() {
+    let (declarations, diagnostics) =
+        resolve("opacity-0 opacity-100 opacity-150 opacity--5 opacity-NaN");
+    assert_eq!(
+        declarations,
+        [Declaration::Opacity(0.), Declaration::Opacity(1.)]
+    );
+    assert_eq!(
+        diagnostics,
+        ["opacity-150", "opacity--5", "opacity-NaN"]
+            .map(|class_name| Diagnostic::InvalidValue(class_name.to_string()))
+    );
+}
+
+#[test]
+fn theme_classes_go_first() {
+    let attributes = vec![("class".to_string(), "font-mono mb-0".to_string())];
+    let style = ResolvedStyle::resolve("p", &attributes, &Theme::default());
+    assert_eq!(style.font.as_deref(), Some("Menlo"));
+    assert_eq!(
+        style.declarations,
+        [
+            Declaration::Class("mb-4".to_string()),
+            Declaration::Class("mb-0".to_string()),
+        ]
+    );
+
+    let style = ResolvedStyle::resolve("p", &attributes, &Theme::empty());
+    assert_eq!(style.declarations, [Declaration::Class("mb-0".to_string())]);
+}
@@ -0,0 +1,146 @@
+use xml2gpui::diagnostics::{collect_diagnostics, Diagnostic};
+use xml2gpui::template::resolve_templates;
+use xml2gpui::tree::{try_parse_xml, Component};
+
+fn resolve(xml: &str) -> (Component, Vec<Diagnostic>) {
+    collect_diagnostics(|| resolve_templates(try_parse_xml(xml).unwrap()))
+}
+
+fn xml(xml: &str) -> Component {
+    try_parse_xml(xml).unwrap()
+}
+
+#[test]
+fn props_are_substituted_in_text_and_attributes() {
+    let (root, diagnostics) = resolve(
+        r#"<div>
+            <template name="badge" props="label, color">
+                <span class="bg-{{color}} p-1" title="{{label}}">{{ label }}!</span>
+            </template>
+            <badge label="New" color="[#ff0000]"/>
+        </div>"#,
+    );
+    assert_eq!(diagnostics, []);
+    assert_eq!(
+        root,
+        xml(r#"<div><span class="bg-[#ff0000] p-1" title="New">New!</span></div>"#)
+    );
+}
+
+#[test]
+fn missing_props_are_empty_and_unknown_placeholders_stay() {
+    let (root, _) = resolve(
+        r#"<div>
+            <template name="label" props="text"><p>{{text}}|{{other}}</p></template>
+            <label/>
+        </div>"#,
+    );
+    assert_eq!(root, xml("<div><p>|{{other}}</p></div>"));
+}
+
+#[test]
+fn children_fill_the_default_and_named_slots() {
+    let (root, _) = resolve(
+        r#"<div>
+            <template name="card">
+                <div class="card"><div class="header"><slot name="header"/></div><slot/></div>
+            </template>
+            <card><p>body</p><b slot="header">Title</b><p>more</p></card>
+        </div>"#,
+    );
+    assert_eq!(
+        root,
+        xml(
+            r#"<div><div class="card"><div class="header"><b>Title</b></div><p>body</p><p>more</p></div></div>"#
+        )
+    );
+}
+
+#[test]
+fn text_content_goes_to_the_default_slot() {
+    let (root, _) = resolve(
+        r#"<div><template name="button"><div class="btn"><slot/></div></template><button>Save</button></div>"#,
+    );
+    let button = &root.children[0];
+    assert_eq!(button.children.len(), 1);
+    assert!(button.children[0].is_text_node());
+    assert_eq!(button.children[0].text.as_deref(), Some("Save"));
+}
+
+#[test]
+fn empty_slots_use_their_fallback_content() {
+    let (root, _) = resolve(
+        r#"<div>
+            <template name="panel" props="title">
+                <div><slot name="header"><h2>{{title}}</h2></slot><slot><p>Empty</p></slot></div>
+            </template>
+            <panel title="Logs"/>
+        </div>"#,
+    );
+    assert_eq!(root, xml("<div><div><h2>Logs</h2><p>Empty</p></div></div>"));
+}
+
+#[test]
+fn other_attributes_go_to_the_root_of_the_body() {
+    let (root, _) = resolve(
+        r#"<div>
+            <template name="card" props="title"><div class="rounded p-4" id="inner">{{title}}</div></template>
+            <card title="Stats" class="mt-2" id="outer" tabindex="0"/>
+        </div>"#,
+    );
+    assert_eq!(
+        root,
+        xml(r#"<div><div class="rounded p-4 mt-2" id="inner" tabindex="0">Stats</div></div>"#)
+    );
+}
+
+#[test]
+fn templates_can_use_other_templates() {
+    let (root, diagnostics) = resolve(
+        r#"<div>
+            <template name="icon" props="name"><img src="{{name}}.svg"/></template>
+            <template name="item" props="icon"><li><icon name="{{icon}}"/><slot/></li></template>
+            <ul><item icon="home">Home</item></ul>
+        </div>"#,
+    );
+    assert_eq!(diagnostics, []);
+    assert_eq!(
+        root,
+        xml(r#"<div><ul><li><img src="home.svg"/>Home</li></ul></div>"#)
+    );
+}
+
+#[test]
+fn deeply_nested_uses_are_expanded_once() {
+    let depth = 200;
+    let uses = "<box>".repeat(depth) + "<b/>" + &"</box>".repeat(depth);
+    let (root, diagnostics) = resolve(&format!(
+        r#"<div><template name="box"><div class="p-1"><slot/></div></template>{}</div>"#,
+        uses
+    ));
+    assert_eq!(diagnostics, []);
+    let expected = r#"<div class="p-1">"#.repeat(depth) + "<b/>" + &"</div>".repeat(depth);
+    assert_eq!(root, xml(&format!("<div>{}</div>", expected)));
+}
+
+#[test]
+fn recursion_is_reported() {
+    let (root, diagnostics) = resolve(
+        r#"<div>
+            <template name="a"><div><b/><a/></div></template>
+            <a/>
+        </div>"#,
+    );
+    assert_eq!(root, xml("<div><div><b/></div></div>"));
+    assert_eq!(
+        diagnostics,
+        [Diagnostic::Element("Template a uses itself".to_string())]
+    );
+}
+
+#[test]
+fn template_without_name_is_a_parse_error() {
+    let error = try_parse_xml("<div>\n  <template><p/></template>\n</div>").unwrap_err();
+    assert_eq!(error.message, "<template> must have name attribute");
+    assert_eq!((error.line, error.column), (2, 3));
+}
@@ -0,0 +1,90 @@
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::style::{Color, Declaration};
+use xml2gpui::text::build_paragraph;
+use xml2gpui::theme::Theme;
+use xml2gpui::tree::try_parse_xml;
+
+// Text of the paragraph the children of the root form
+fn paragraph(xml: &str) -> String {
+    let root = try_parse_xml(xml).unwrap();
+    build_paragraph(&root.children, &Theme::default())
+        .unwrap()
+        .text
+}
+
+#[test]
+fn spaces_next_to_inline_elements_are_kept() {
+    assert_eq!(
+        paragraph("<p>Click <a href=\"x\">here</a> now</p>"),
+        "Click here now"
+    );
+    assert_eq!(
+        paragraph("<p>Click<a href=\"x\"> here </a>now</p>"),
+        "Click here now"
+    );
+    assert_eq!(paragraph("<p><b>Bold</b> <i>italic</i></p>"), "Bold italic");
+    assert_eq!(
+        paragraph("<p><b>Bold </b><i> italic</i>.</p>"),
+        "Bold italic."
+    );
+    assert_eq!(paragraph("<p>a <b> <i> b </i> </b> c</p>"), "a b c");
+}
+
+#[test]
+fn whitespace_in_inline_elements_is_collapsed() {
+    assert_eq!(
+        paragraph("<p>Some <b>\n    bold\n    text\n  </b>here</p>"),
+        "Some bold text here"
+    );
+    assert_eq!(paragraph("<p><i>\tone\t\ttwo</i></p>"), "one two");
+}
+
+#[test]
+fn paragraphs_are_trimmed_at_their_ends() {
+    assert_eq!(paragraph("<p>\n  <b> bold </b>\n</p>"), "bold");
+    assert_eq!(paragraph("<p>line<br/>  next</p>"), "line\nnext");
+}
+
+#[test]
+fn links_cover_their_text() {
+    let root = try_parse_xml("<p>Click <a href=\"x\"> here </a>now</p>").unwrap();
+    let paragraph = build_paragraph(&root.children, &Theme::default()).unwrap();
+    assert_eq!(paragraph.text, "Click here now");
+    let (range, url) = &paragraph.links[0];
+    assert_eq!(&paragraph.text[range.clone()], "here ");
+    assert_eq!(url, "x");
+}
+
+#[test]
+fn inline_elements_resolve_classes_like_blocks() {
+    let root = try_parse_xml(
+        r#"<p><span class="text-lg font-mono p-4">big</span> <b class="text-color-[#ff0000]">red</b></p>"#,
+    )
+    .unwrap();
+    let (paragraph, diagnostics) =
+        collect_diagnostics(|| build_paragraph(&root.children, &Theme::default()).unwrap());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+
+    let (_, span) = &paragraph.runs[0];
+    assert_eq!(span.font.as_deref(), Some("Menlo"));
+    assert!(span
+        .declarations
+        .contains(&Declaration::Class("text-lg".to_string())));
+    let (_, bold) = &paragraph.runs[2];
+    assert_eq!(
+        bold.declarations,
+        [
+            Declaration::Class("font-bold".to_string()),
+            Declaration::TextColor(Color(0xff0000ff)),
+        ]
+    );
+}
+
+#[test]
+fn code_uses_the_mono_family_of_the_theme() {
+    let root = try_parse_xml("<p><code>let x</code></p>").unwrap();
+    let theme = Theme::default().with_font_family("font-mono", "Fira Code");
+    let paragraph = build_paragraph(&root.children, &theme).unwrap();
+    let (_, style) = &paragraph.runs[0];
+    assert_eq!(style.font.as_deref(), Some("Fira Code"));
+}
@@ -0,0 +1,78 @@
+use xml2gpui::tree::{
+    element_key, try_parse_xml, try_parse_xml_with_comments, Component, ElementKeys,
+};
+
+// Keys of all nodes in document order
+fn keys(root: &Component) -> Vec<String> {
+    fn collect(component: &Component, path: &mut Vec<usize>, keys: &mut Vec<String>) {
+        keys.push(element_key(component, path));
+        for (i, child) in component.children.iter().enumerate() {
+            path.push(i);
+            collect(child, path, keys);
+            path.pop();
+        }
+    }
+    let mut keys = Vec::new();
+    collect(root, &mut Vec::new(), &mut keys);
+    keys
+}
+
+#[test]
+fn nodes_without_id_are_keyed_by_path() {
+    let root = try_parse_xml("<div><p>a</p><div><span/><b/></div></div>").unwrap();
+    assert_eq!(keys(&root), ["/", "/0", "/1", "/1/0", "/1/1"]);
+}
+
+#[test]
+fn ids_stay_the_same_when_nodes_move() {
+    let before = try_parse_xml(r#"<div><p id="a"/><p id="b"/></div>"#).unwrap();
+    let after = try_parse_xml(r#"<div><p>new</p><p id="b"/><div><p id="a"/></div></div>"#).unwrap();
+    assert_eq!(keys(&before), ["/", "a", "b"]);
+    assert_eq!(keys(&after), ["/", "/0", "b", "/2", "a"]);
+}
+
+#[test]
+fn keys_are_the_same_for_equal_trees() {
+    let xml = r#"<div id="root"><ul><li>one</li><li id="two">two</li></ul></div>"#;
+    let first = try_parse_xml(xml).unwrap();
+    let second = try_parse_xml(&format!("\n\n{}", xml)).unwrap();
+    assert_eq!(keys(&first), keys(&second));
+    assert_eq!(keys(&first), ["root", "/0", "/0/0", "two"]);
+}
+
+#[test]
+fn inline_elements_keep_their_whitespace_collapsed() {
+    let root = try_parse_xml("<p>Click <a href=\"x\">\n  here\n</a>now</p>").unwrap();
+    assert_eq!(root.children[0].text.as_deref(), Some("Click "));
+    assert_eq!(root.children[1].text.as_deref(), Some(" here "));
+    assert_eq!(root.children[2].text.as_deref(), Some("now"));
+
+    // Blocks are trimmed
+    let root = try_parse_xml("<div><p>\n  text\n</p><b> </b></div>").unwrap();
+    assert_eq!(root.children[0].text.as_deref(), Some("text"));
+    assert_eq!(root.children[1].text.as_deref(), Some(" "));
+}
+
+#[test]
+fn comments_dont_change_the_whitespace_after_inline_elements() {
+    // Without the comment the space would follow <b> directly
+    let root = try_parse_xml_with_comments("<span><b/><!----> </span>").unwrap();
+    assert_eq!(root.children.len(), 3);
+    assert_eq!(root.children[2].text.as_deref(), Some(" "));
+}
+
+#[test]
+fn rows_of_virtual_lists_keep_their_keys() {
+    let root = try_parse_xml(
+        r#"<div><p>text</p><virtual-list source="logs"><div><input/></div></virtual-list></div>"#,
+    )
+    .unwrap();
+    let keys = ElementKeys::new(&root);
+    assert!(keys.contains("/0"));
+    assert!(keys.contains("/1/0/0"));
+    // The input of row 7, rendered from the template at /1/0
+    assert!(keys.contains("/1/7/0/0"));
+    assert!(!keys.contains("/0/3"));
+    assert!(!keys.contains("/2"));
+    assert!(!keys.contains("/10/0"));
+}
@@ -0,0 +1,54 @@
+// Run with `cargo test -p xml2gpui --features test-support`
+#![cfg(feature = "test-support")]
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use gpui::{div, IntoElement, Render, TestAppContext, ViewContext};
+use xml2gpui::document::{EmbeddedLoader, FsLoader, XmlLoader};
+use xml2gpui::view::XmlView;
+
+// Window for the views under test to be created in
+struct EmptyView;
+
+impl Render for EmptyView {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+    }
+}
+
+#[gpui::test]
+fn views_load_their_document_through_the_loader(cx: &mut TestAppContext) {
+    let loader = EmbeddedLoader::new()
+        .with_file("main.xml", r#"<div><include src="header.xml"/></div>"#)
+        .with_file("header.xml", "<h1>Title</h1>");
+    let (_, cx) = cx.add_window_view(|_| EmptyView);
+    let view = cx.update(|cx| XmlView::with_loader("main.xml", Arc::new(loader), cx));
+    cx.update(|cx| {
+        let view = view.read(cx);
+        assert!(view.error().is_none());
+        assert_eq!(
+            view.files(),
+            [PathBuf::from("main.xml"), PathBuf::from("header.xml")]
+        );
+        assert_eq!(view.root().children[0].elem, "h1");
+    });
+}
+
+#[gpui::test]
+fn views_show_an_error_for_markup_that_does_not_parse(cx: &mut TestAppContext) {
+    let loader = EmbeddedLoader::new().with_file("main.xml", "<div><p></div>");
+    let (_, cx) = cx.add_window_view(|_| EmptyView);
+    let view = cx.update(|cx| XmlView::with_loader("main.xml", Arc::new(loader), cx));
+    cx.update(|cx| {
+        let view = view.read(cx);
+        assert!(view.error().is_some());
+        assert_eq!(view.files(), [PathBuf::from("main.xml")]);
+    });
+}
+
+#[test]
+fn only_files_on_disk_are_watched() {
+    assert!(FsLoader.is_watchable());
+    assert!(!EmbeddedLoader::new().is_watchable());
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use xml2gpui::document::{load_document, FsLoader};
+use xml2gpui::lint::{lint, LintMessage, Severity};
+use xml2gpui::tree::{collect_templates, try_parse_xml, Location, ParseError};
+
+pub fn run(args: &[String]) -> ExitCode {
+    let mut deny_warnings = false;
+    let mut files = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--deny-warnings" => deny_warnings = true,
+            option if option.starts_with('-') => {
+                eprintln!("Unknown option: {}", option);
+                return ExitCode::from(2);
+            }
+            file => files.push(PathBuf::from(file)),
+        }
+    }
+    if files.is_empty() {
+        eprintln!("No files to check");
+        return ExitCode::from(2);
+    }
+
+    let mut report = Report::default();
+    for file in &files {
+        check_file(file, &mut report);
+    }
+
+    eprintln!("{} errors, {} warnings", report.errors, report.warnings);
+    if report.errors > 0 || (deny_warnings && report.warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check_file(path: &Path, report: &mut Report) {
+    let xml = match std::fs::read_to_string(path) {
+        Ok(xml) => xml,
+        Err(e) => {
+            let error = ParseError::new(format!("Could not read file: {}", e));
+            report.parse_error(path, &error);
+            return;
+        }
+    };
+    let root = match try_parse_xml(&xml) {
+        Ok(root) => root,
+        Err(error) => {
+            report.parse_error(path, &error);
+            return;
+        }
+    };
+
+    // Templates may be defined in included files, so they are collected from the whole document
+    let templates: HashSet<String> = match load_document(path, &FsLoader) {
+        Ok(mut document) => collect_templates(&mut document.root).into_keys().collect(),
+        Err(error) => {
+            // The file itself parsed, so this is about one of its includes
+            report.parse_error(path, &error);
+            HashSet::new()
+        }
+    };
+
+    for message in lint(&root, &templates) {
+        report.message(path, &message);
+    }
+}
+
+#[derive(Default)]
+struct Report {
+    errors: usize,
+    warnings: usize,
+    // Errors in included files show up once for every file including them
+    printed: HashSet<String>,
+}
+
+impl Report {
+    // Errors name the file they are in, which may be an include of `path`
+    fn parse_error(&mut self, path: &Path, error: &ParseError) {
+        let message = LintMessage {
+            severity: Severity::Error,
+            message: error.message.clone(),
+            location: (error.line > 0).then_some(Location {
+                line: error.line,
+                column: error.column,
+            }),
+        };
+        self.message(error.file.as_deref().unwrap_or(path), &message);
+    }
+
+    fn message(&mut self, path: &Path, message: &LintMessage) {
+        let line = match message.location {
+            Some(_) => format!("{}:{}", path.display(), message),
+            None => format!("{}: {}", path.display(), message),
+        };
+        if !self.printed.insert(line.clone()) {
+            return;
+        }
+        println!("{}", line);
+        match message.severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+        }
+    }
+}
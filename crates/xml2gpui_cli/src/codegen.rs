@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use xml2gpui::codegen::generate;
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::document::{load_document, FsLoader};
+use xml2gpui::template::resolve_templates;
+use xml2gpui::theme::Theme;
+
+pub fn run(args: &[String]) -> ExitCode {
+    let mut output: Option<PathBuf> = None;
+    let mut name: Option<String> = None;
+    let mut file: Option<PathBuf> = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--name" => {
+                let Some(value) = args.next() else {
+                    eprintln!("Missing value for {}", arg);
+                    return ExitCode::from(2);
+                };
+                if arg == "-o" {
+                    output = Some(PathBuf::from(value));
+                } else {
+                    name = Some(value.clone());
+                }
+            }
+            option if option.starts_with('-') => {
+                eprintln!("Unknown option: {}", option);
+                return ExitCode::from(2);
+            }
+            path if file.is_none() => file = Some(PathBuf::from(path)),
+            _ => {
+                eprintln!("codegen takes a single file");
+                return ExitCode::from(2);
+            }
+        }
+    }
+    let Some(file) = file else {
+        eprintln!("No file to generate code for");
+        return ExitCode::from(2);
+    };
+
+    let document = match load_document(&file, &FsLoader) {
+        Ok(document) => document,
+        Err(error) => {
+            // Errors from load_document name the file they are in
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let root = resolve_templates(document.root);
+    let name = name.unwrap_or_else(|| function_name(&file));
+    let (source, diagnostics) = collect_diagnostics(|| generate(&root, &name, &Theme::default()));
+    // The source may go to stdout, so what could not be generated is reported on stderr
+    for diagnostic in &diagnostics {
+        eprintln!("{}: warning: {}", file.display(), diagnostic);
+    }
+
+    match output {
+        Some(output) => {
+            if let Err(e) = std::fs::write(&output, source) {
+                eprintln!("Could not write {}: {}", output.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{}", source),
+    }
+    ExitCode::SUCCESS
+}
+
+// Function name derived from the file name, e.g. "settings-panel.html" to "settings_panel"
+fn function_name(file: &Path) -> String {
+    let stem = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    match name.chars().next() {
+        Some(c) if !c.is_ascii_digit() => name,
+        _ => format!("render_{}", name),
+    }
+}
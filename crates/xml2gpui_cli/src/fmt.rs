@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use xml2gpui::format::format_xml;
+
+pub fn run(args: &[String]) -> ExitCode {
+    let mut check = false;
+    let mut files = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--check" => check = true,
+            option if option.starts_with('-') => {
+                eprintln!("Unknown option: {}", option);
+                return ExitCode::from(2);
+            }
+            file => files.push(PathBuf::from(file)),
+        }
+    }
+    if files.is_empty() {
+        eprintln!("No files to format");
+        return ExitCode::from(2);
+    }
+
+    let mut failed = false;
+    for file in &files {
+        let xml = match std::fs::read_to_string(file) {
+            Ok(xml) => xml,
+            Err(e) => {
+                eprintln!("{}: Could not read file: {}", file.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        let formatted = match format_xml(&xml) {
+            Ok(formatted) => formatted,
+            Err(error) => {
+                eprintln!("{}", error.with_file(file));
+                failed = true;
+                continue;
+            }
+        };
+        if formatted == xml {
+            continue;
+        }
+
+        if check {
+            println!("{}", file.display());
+            failed = true;
+        } else if let Err(e) = std::fs::write(file, formatted) {
+            eprintln!("Could not write {}: {}", file.display(), e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,33 @@
+use std::process::ExitCode;
+
+mod check;
+mod codegen;
+mod fmt;
+
+const USAGE: &str = "Usage: xml2gpui <command> [options] <files>...
+
+Commands:
+  check    Report parse errors, unknown elements and unrecognized classes
+           --deny-warnings  Fail on warnings too
+  codegen  Convert a markup file into a Rust function returning its GPUI elements
+           -o <file>        Write the function to a file instead of stdout
+           --name <name>    Name of the function, the file name by default
+  fmt      Rewrite markup files in the canonical format, with classes in Tailwind order
+           --check          List the files that are not formatted instead, failing if any";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("check") => check::run(&args[1..]),
+        Some("codegen") => codegen::run(&args[1..]),
+        Some("fmt") => fmt::run(&args[1..]),
+        Some("-h") | Some("--help") | None => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Some(command) => {
+            eprintln!("Unknown command: {}\n\n{}", command, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,379 @@
+use std::collections::{HashMap, HashSet};
+
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Hover, HoverContents, MarkupContent,
+    MarkupKind, Position, Range, TextEdit,
+};
+
+use xml2gpui::classes::{tailwind_method, OTHER_CLASSES, TAILWIND_CLASSES, VALUE_CLASSES};
+use xml2gpui::lint::{check_classes, SPECIAL_ELEMENTS};
+use xml2gpui::tree::{BLOCK_ELEMENTS, CONTROL_ELEMENTS, INLINE_ELEMENTS};
+
+use crate::position::{offset_at, position_at};
+
+const COMMON_ATTRIBUTES: &[(&str, &str)] = &[
+    ("class", "Tailwind classes"),
+    (
+        "id",
+        "Keeps the element state, like hover and scroll offset, across reloads",
+    ),
+    ("font", "Font family"),
+    ("tabindex", "Makes the element focusable"),
+    ("scroll-to", "Id of an element to scroll into view"),
+    ("slot", "Name of the template slot the element goes in"),
+];
+
+// Where in the markup the cursor is
+enum Context {
+    // Typing an element name after "<"
+    Element,
+    // Inside a start tag, outside of attribute values
+    Attribute { elem: String },
+    // Inside a quoted attribute value; `word` is the part of the value before the cursor
+    // since the last whitespace
+    Value { attribute: String, word: String },
+    Content,
+}
+
+fn context(text: &str, offset: usize) -> Context {
+    let before = &text[..offset];
+    let Some(open) = before.rfind('<') else {
+        return Context::Content;
+    };
+    let tag = &before[open + 1..];
+    if tag.contains('>') || tag.starts_with('!') || tag.starts_with('?') {
+        return Context::Content;
+    }
+    let tag = tag.strip_prefix('/').unwrap_or(tag);
+    if tag.chars().all(|c| c.is_alphanumeric() || c == '-') {
+        return Context::Element;
+    }
+    let elem = tag.split(char::is_whitespace).next().unwrap_or_default();
+
+    // Find the attribute whose value the cursor is in, if any
+    let mut quote = None;
+    let mut name = String::new();
+    let mut attribute = String::new();
+    let mut value_start = 0;
+    let mut new_word = true;
+    for (i, c) in tag.char_indices().skip(elem.chars().count()) {
+        match (quote, c) {
+            (Some(q), c) if c == q => {
+                quote = None;
+                new_word = true;
+            }
+            (Some(_), _) => {}
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                attribute = name.clone();
+                value_start = i + 1;
+            }
+            (None, c) if c == '=' || c.is_whitespace() => new_word = true,
+            (None, c) => {
+                if new_word {
+                    name.clear();
+                    new_word = false;
+                }
+                name.push(c);
+            }
+        }
+    }
+
+    match quote {
+        Some(_) => {
+            let value = &tag[value_start..];
+            let word = value.rsplit(char::is_whitespace).next().unwrap_or_default();
+            Context::Value {
+                attribute,
+                word: word.to_string(),
+            }
+        }
+        None => Context::Attribute {
+            elem: elem.to_string(),
+        },
+    }
+}
+
+pub fn complete(text: &str, position: Position) -> Vec<CompletionItem> {
+    let offset = offset_at(text, position);
+    match context(text, offset) {
+        Context::Element => complete_elements(text),
+        Context::Attribute { elem } => complete_attributes(text, &elem),
+        Context::Value { attribute, word } if attribute == "class" => {
+            let start = position_at(text, offset - word.len());
+            complete_classes(&word, Range::new(start, position))
+        }
+        Context::Value { .. } | Context::Content => Vec::new(),
+    }
+}
+
+fn complete_elements(text: &str) -> Vec<CompletionItem> {
+    let kinds = [
+        (BLOCK_ELEMENTS, "block element"),
+        (INLINE_ELEMENTS, "inline element"),
+        (CONTROL_ELEMENTS, "form control"),
+        (SPECIAL_ELEMENTS, "xml2gpui element"),
+    ];
+    let mut items: Vec<CompletionItem> = kinds
+        .iter()
+        .flat_map(|(elements, detail)| {
+            elements.iter().map(move |elem| CompletionItem {
+                label: elem.to_string(),
+                kind: Some(CompletionItemKind::KEYWORD),
+                detail: Some(detail.to_string()),
+                ..Default::default()
+            })
+        })
+        .collect();
+
+    items.extend(templates(text).into_keys().map(|name| CompletionItem {
+        label: name,
+        kind: Some(CompletionItemKind::CLASS),
+        detail: Some("template".to_string()),
+        ..Default::default()
+    }));
+
+    // Templates may be named like built-in elements
+    let mut seen = HashSet::new();
+    items.retain(|item| seen.insert(item.label.clone()));
+    items
+}
+
+fn complete_attributes(text: &str, elem: &str) -> Vec<CompletionItem> {
+    let mut attributes: Vec<(String, String)> = COMMON_ATTRIBUTES
+        .iter()
+        .chain(element_attributes(elem))
+        .map(|(name, detail)| (name.to_string(), detail.to_string()))
+        .collect();
+    if let Some(props) = templates(text).remove(elem) {
+        attributes.extend(
+            props
+                .into_iter()
+                .map(|prop| (prop, format!("prop of {}", elem))),
+        );
+    }
+
+    attributes
+        .into_iter()
+        .map(|(name, detail)| CompletionItem {
+            insert_text: Some(format!("{}=\"\"", name)),
+            label: name,
+            kind: Some(CompletionItemKind::PROPERTY),
+            detail: Some(detail),
+            ..Default::default()
+        })
+        .collect()
+}
+
+// Templates of the document with their props. The document usually doesn't parse while an
+// element is being typed, so they are looked up in the text.
+fn templates(text: &str) -> HashMap<String, Vec<String>> {
+    text.match_indices("<template")
+        .filter_map(|(i, _)| {
+            let tag = &text[i..];
+            let tag = &tag[..tag.find('>')?];
+            let name = attribute_value(tag, "name")?;
+            let props = attribute_value(tag, "props")
+                .unwrap_or_default()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|prop| !prop.is_empty())
+                .map(str::to_string)
+                .collect();
+            Some((name.to_string(), props))
+        })
+        .collect()
+}
+
+// Value of an attribute in the text of a start tag, in single or double quotes
+fn attribute_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    tag.match_indices(name).find_map(|(i, _)| {
+        if !tag[..i].ends_with(char::is_whitespace) {
+            return None;
+        }
+        let rest = tag[i + name.len()..]
+            .trim_start()
+            .strip_prefix('=')?
+            .trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &rest[1..];
+        Some(&value[..value.find(quote)?])
+    })
+}
+
+fn element_attributes(elem: &str) -> &'static [(&'static str, &'static str)] {
+    match elem {
+        "img" => &[("src", "Image file")],
+        "include" => &[("src", "Markup file, relative to this one")],
+        "svg" => &[("path", "SVG file")],
+        "a" => &[("href", "Link target")],
+        "ol" => &[("start", "Number of the first item")],
+        "virtual-list" => &[
+            ("source", "Name of the ListSource providing the rows"),
+            ("item-height", "Height of each row in pixels"),
+        ],
+        "template" => &[
+            ("name", "Element name the template is used with"),
+            ("props", "Attributes available as {{prop}} in the body"),
+        ],
+        "slot" => &[("name", "Children marked slot=\"name\" go here")],
+        "option" => &[("value", "Value when selected, the label by default")],
+        "button" => &[("on-click", "Handler called on click")],
+        "checkbox" | "toggle" => &[
+            ("checked", "Bound value, e.g. {{name}}"),
+            ("on-change", "Handler called when toggled"),
+        ],
+        "input" => &[
+            ("value", "Bound value, e.g. {{name}}"),
+            ("placeholder", "Text shown while empty"),
+            ("on-change", "Handler called on each edit"),
+            ("on-submit", "Handler called on Enter"),
+        ],
+        "select" => &[
+            ("value", "Bound value, e.g. {{name}}"),
+            ("on-change", "Handler called when an option is chosen"),
+        ],
+        _ => &[],
+    }
+}
+
+// Classes starting with the word being typed. Clients would split the word at "-" or "[",
+// so the items replace the whole word.
+fn complete_classes(word: &str, range: Range) -> Vec<CompletionItem> {
+    let table = TAILWIND_CLASSES
+        .iter()
+        .map(|(class, method)| (*class, format!(".{}", method)));
+    let others = OTHER_CLASSES
+        .iter()
+        .chain(VALUE_CLASSES)
+        .map(|(class, detail)| (*class, detail.to_string()));
+
+    table
+        .chain(others)
+        .filter(|(class, _)| class.starts_with(word))
+        .map(|(class, detail)| CompletionItem {
+            label: class.to_string(),
+            kind: Some(CompletionItemKind::VALUE),
+            detail: Some(detail),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                range,
+                class.to_string(),
+            ))),
+            ..Default::default()
+        })
+        .collect()
+}
+
+// The GPUI method of the class under the cursor, or what is wrong with it
+pub fn hover(text: &str, position: Position) -> Option<Hover> {
+    let offset = offset_at(text, position);
+    let Context::Value { attribute, .. } = context(text, offset) else {
+        return None;
+    };
+    if attribute != "class" {
+        return None;
+    }
+
+    let is_class_char = |c: char| !c.is_whitespace() && c != '"' && c != '\'';
+    let start = text[..offset]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !is_class_char(*c))
+        .map_or(0, |(i, c)| i + c.len_utf8());
+    let end = text[offset..]
+        .find(|c: char| !is_class_char(c))
+        .map_or(text.len(), |i| offset + i);
+    let class_name = &text[start..end];
+    if class_name.is_empty() {
+        return None;
+    }
+
+    let value = match tailwind_method(class_name) {
+        Some(method) => format!("```rust\n.{}\n```", method),
+        None => {
+            let problems = check_classes(class_name);
+            if problems.is_empty() {
+                format!("`{}` is applied by xml2gpui", class_name)
+            } else {
+                problems
+                    .into_iter()
+                    .map(|(_, message)| message)
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+        }
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(Range::new(position_at(text, start), position_at(text, end))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Completions with the cursor at the "|" in the text
+    fn completions(text: &str) -> Vec<String> {
+        let offset = text.find('|').unwrap();
+        let text = text.replace('|', "");
+        complete(&text, position_at(&text, offset))
+            .into_iter()
+            .map(|item| item.label)
+            .collect()
+    }
+
+    #[test]
+    fn elements_include_templates_once() {
+        let labels = completions(r#"<div><template name="card"/><template name="p"/><|"#);
+        assert!(labels.contains(&"card".to_string()));
+        assert_eq!(labels.iter().filter(|label| *label == "p").count(), 1);
+    }
+
+    #[test]
+    fn attributes_include_element_attributes_and_props() {
+        let labels = completions(r#"<template name='card' props="title, icon"/><card |"#);
+        for name in ["class", "id", "title", "icon"] {
+            assert!(labels.contains(&name.to_string()), "{}", name);
+        }
+        assert!(completions("<img |").contains(&"src".to_string()));
+    }
+
+    #[test]
+    fn classes_complete_the_word_being_typed() {
+        let labels = completions(r#"<div class="flex text-|"#);
+        assert!(labels.contains(&"text-xs".to_string()));
+        assert!(labels.contains(&"text-color-[#".to_string()));
+        assert!(labels.contains(&"text-9xl".to_string()));
+        assert!(labels.iter().all(|label| label.starts_with("text-")));
+        // Not in other attributes or in content
+        assert_eq!(completions(r#"<div id="text-|"#), Vec::<String>::new());
+        assert_eq!(completions("<div>text-|"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn attribute_values_are_found_with_any_spacing_and_quotes() {
+        let tag = "template\n  name = 'card'\tprops=\"a b\" data-name=\"x\"";
+        assert_eq!(attribute_value(tag, "name"), Some("card"));
+        assert_eq!(attribute_value(tag, "props"), Some("a b"));
+        assert_eq!(attribute_value(tag, "data"), None);
+        assert_eq!(attribute_value("template name", "name"), None);
+    }
+
+    #[test]
+    fn hover_shows_the_method_or_the_problem() {
+        let text = r#"<div class="flex-col z-10">"#;
+        let value = |offset: usize| match hover(text, position_at(text, offset)) {
+            Some(Hover {
+                contents: HoverContents::Markup(markup),
+                ..
+            }) => markup.value,
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(value(14), "```rust\n.flex_col()\n```");
+        assert!(value(21).contains("z-10 is not supported"), "{}", value(21));
+        assert!(hover(text, position_at(text, 3)).is_none());
+    }
+}
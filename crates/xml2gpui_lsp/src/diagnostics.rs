@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, Range};
+
+use xml2gpui::diagnostics::collect_diagnostics;
+use xml2gpui::document::{load_document, FsLoader};
+use xml2gpui::lint::{lint, Severity};
+use xml2gpui::tree::{try_parse_xml, Component};
+
+use crate::position::{offset_of_location, position_at};
+
+// Parse errors, or what `xml2gpui check` reports for the document. `path` is the file of the
+// document, used to find the templates of the files it includes.
+pub fn diagnostics(text: &str, path: Option<&Path>) -> Vec<Diagnostic> {
+    let root = match try_parse_xml(text) {
+        Ok(root) => root,
+        Err(error) => {
+            let start = offset_of_location(text, error.line.max(1), error.column.max(1));
+            return vec![diagnostic(
+                text,
+                start,
+                line_end(text, start),
+                DiagnosticSeverity::ERROR,
+                error.message,
+            )];
+        }
+    };
+
+    let mut template_names: HashSet<String> = templates(&root).into_keys().collect();
+    // Included files are read from disk, the document itself may have unsaved changes
+    if let Some(path) = path {
+        if let Ok(document) = load_document(path, &FsLoader) {
+            template_names.extend(templates(&document.root).into_keys());
+        }
+    }
+
+    let (messages, _) = collect_diagnostics(|| lint(&root, &template_names));
+    messages
+        .into_iter()
+        .map(|message| {
+            let start = message.location.map_or(0, |location| {
+                offset_of_location(text, location.line, location.column)
+            });
+            let severity = match message.severity {
+                Severity::Error => DiagnosticSeverity::ERROR,
+                Severity::Warning => DiagnosticSeverity::WARNING,
+            };
+            diagnostic(text, start, tag_end(text, start), severity, message.message)
+        })
+        .collect()
+}
+
+// Templates defined in a tree by name, with their props. Unlike collect_templates this leaves
+// the tree alone and doesn't print anything for templates without a name.
+fn templates(component: &Component) -> HashMap<String, Vec<String>> {
+    let mut templates = HashMap::new();
+    collect_templates_into(component, &mut templates);
+    templates
+}
+
+fn collect_templates_into(component: &Component, templates: &mut HashMap<String, Vec<String>>) {
+    if component.elem == "template" {
+        if let Some(name) = component.attribute("name") {
+            let props = component
+                .attribute("props")
+                .unwrap_or_default()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|prop| !prop.is_empty())
+                .map(str::to_string)
+                .collect();
+            templates.insert(name.to_string(), props);
+        }
+    }
+    for child in &component.children {
+        collect_templates_into(child, templates);
+    }
+}
+
+fn diagnostic(
+    text: &str,
+    start: usize,
+    end: usize,
+    severity: DiagnosticSeverity,
+    message: String,
+) -> Diagnostic {
+    Diagnostic {
+        range: Range::new(position_at(text, start), position_at(text, end)),
+        severity: Some(severity),
+        source: Some("xml2gpui".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+fn line_end(text: &str, offset: usize) -> usize {
+    text[offset..].find('\n').map_or(text.len(), |i| offset + i)
+}
+
+// End of the start tag beginning at `offset`, so messages about an element underline its tag
+// and attributes
+fn tag_end(text: &str, offset: usize) -> usize {
+    text[offset..]
+        .find('>')
+        .map_or(text.len(), |i| offset + i + 1)
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{Completion, HoverRequest, Request as RequestTrait};
+use lsp_types::{
+    CompletionOptions, CompletionParams, CompletionResponse, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, HoverParams, HoverProviderCapability,
+    PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Url,
+};
+
+mod completion;
+mod diagnostics;
+mod position;
+
+// Language server for xml2gpui markup, talking LSP over stdin and stdout. Nothing else may be
+// printed to stdout, so library calls that report problems run under collect_diagnostics.
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(vec!["<".to_string(), " ".to_string(), "\"".to_string()]),
+            ..Default::default()
+        }),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities)?)?;
+
+    Server::default().run(&connection)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+#[derive(Default)]
+struct Server {
+    // Text of the open documents, which may differ from the files on disk
+    documents: HashMap<Url, String>,
+}
+
+impl Server {
+    fn run(&mut self, connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = self.request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    if let Some(uri) = self.notification(notification) {
+                        self.publish_diagnostics(connection, uri)?;
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn request(&self, request: Request) -> Response {
+        let Request { id, method, params } = request;
+        match method.as_str() {
+            Completion::METHOD => match serde_json::from_value::<CompletionParams>(params) {
+                Ok(params) => {
+                    let position = params.text_document_position;
+                    let items = self
+                        .documents
+                        .get(&position.text_document.uri)
+                        .map(|text| completion::complete(text, position.position))
+                        .unwrap_or_default();
+                    Response::new_ok(id, CompletionResponse::Array(items))
+                }
+                Err(e) => invalid_params(id, e),
+            },
+            HoverRequest::METHOD => match serde_json::from_value::<HoverParams>(params) {
+                Ok(params) => {
+                    let position = params.text_document_position_params;
+                    let hover = self
+                        .documents
+                        .get(&position.text_document.uri)
+                        .and_then(|text| completion::hover(text, position.position));
+                    Response::new_ok(id, hover)
+                }
+                Err(e) => invalid_params(id, e),
+            },
+            _ => Response::new_err(
+                id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request: {}", method),
+            ),
+        }
+    }
+
+    // Updates the documents and returns the one whose diagnostics need to be published
+    fn notification(&mut self, notification: Notification) -> Option<Url> {
+        let Notification { method, params } = notification;
+        match method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(params).ok()?;
+                let uri = params.text_document.uri;
+                self.documents
+                    .insert(uri.clone(), params.text_document.text);
+                Some(uri)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(params).ok()?;
+                // Full sync: the last change has the whole text
+                let text = params.content_changes.into_iter().last()?.text;
+                let uri = params.text_document.uri;
+                self.documents.insert(uri.clone(), text);
+                Some(uri)
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(params).ok()?;
+                self.documents.remove(&params.text_document.uri);
+                // Publishing for a closed document clears its diagnostics
+                Some(params.text_document.uri)
+            }
+            _ => None,
+        }
+    }
+
+    fn publish_diagnostics(
+        &self,
+        connection: &Connection,
+        uri: Url,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let path = uri.to_file_path().ok();
+        let diagnostics = match self.documents.get(&uri) {
+            Some(text) => diagnostics::diagnostics(text, path.as_deref()),
+            None => Vec::new(),
+        };
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        connection
+            .sender
+            .send(Message::Notification(Notification::new(
+                PublishDiagnostics::METHOD.to_string(),
+                params,
+            )))?;
+        Ok(())
+    }
+}
+
+fn invalid_params(id: RequestId, error: serde_json::Error) -> Response {
+    Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string())
+}
@@ -0,0 +1,83 @@
+use lsp_types::Position;
+
+// LSP positions count UTF-16 code units within a line, the parser reports 1-based lines and
+// columns in characters, and the text is indexed by bytes.
+
+// Byte offset of an LSP position, clamped to the end of its line
+pub fn offset_at(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= position.character {
+            return line_start + i;
+        }
+        units += c.len_utf16() as u32;
+    }
+    line_start + line.len()
+}
+
+pub fn position_at(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let character = before[line_start..].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
+
+// Byte offset of a location reported by the parser
+pub fn offset_of_location(text: &str, line: usize, column: usize) -> usize {
+    let line_start = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>();
+    let line_text = &text[line_start..];
+    line_start
+        + line_text
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(line_text.len(), |(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_count_utf16_units() {
+        let text = "<p>\n  ä😀x</p>";
+        // ä is one unit and two bytes, 😀 two units and four bytes
+        let x = text.find('x').unwrap();
+        assert_eq!(position_at(text, x), Position::new(1, 5));
+        assert_eq!(offset_at(text, Position::new(1, 5)), x);
+        assert_eq!(
+            offset_at(text, Position::new(1, 3)),
+            text.find('😀').unwrap()
+        );
+    }
+
+    #[test]
+    fn offsets_are_clamped_to_the_line_and_text() {
+        let text = "ab\ncd";
+        assert_eq!(offset_at(text, Position::new(0, 10)), 2);
+        assert_eq!(offset_at(text, Position::new(5, 0)), text.len());
+        assert_eq!(position_at(text, text.len()), Position::new(1, 2));
+    }
+
+    #[test]
+    fn parser_locations_count_characters_from_1() {
+        let text = "<div>\n  <é x>";
+        assert_eq!(offset_of_location(text, 2, 3), text.find('<').unwrap() + 8);
+        assert_eq!(offset_of_location(text, 2, 5), text.find(" x").unwrap());
+        assert_eq!(offset_of_location(text, 1, 1), 0);
+    }
+}
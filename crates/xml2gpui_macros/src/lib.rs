@@ -64,38 +64,10 @@ pub fn tailwind_to_gpui(input: TokenStream) -> TokenStream {
 
     let tailwind_matches = tailwind_class_groups.iter().flat_map(|group| {
         group.iter().map(|class| {
-            // Replace "-" to "_" and "/" to "_" in class name
-            let method_name = Ident::new(
-                class
-                    .value()
-                    .replace("-", "_")
-                    .replace("/", "_")
-                    .replace(".", "p")
-                    .as_str(),
-                class.span(),
-            );
-
-            // Fonts are little bit different
-            if class.value().starts_with("font-") {
-                let font_weight = Ident::new(match class.value().replace("font-", "").as_str() {
-                    "thin" => "THIN",
-                    "extralight" => "EXTRA_LIGHT",
-                    "light" => "LIGHT",
-                    "normal" => "NORMAL",
-                    "medium" => "MEDIUM",
-                    "semibold" => "SEMIBOLD",
-                    "bold" => "BOLD",
-                    "extrabold" => "EXTRA_BOLD",
-                    "black" => "BLACK",
-                    _ => "NORMAL",
-                }, class.span());
-                quote! {
-                    #class => #element_name.font_weight(FontWeight::#font_weight),
-                }
-            } else {
-                quote! {
-                    #class => #element_name.#method_name(),
-                }
+            let method_call: Expr = syn::parse_str(&method_call(&class.value()))
+                .expect("method call of a class is a valid expression");
+            quote! {
+                #class => #element_name.#method_call,
             }
         })
     });
@@ -109,3 +81,68 @@ pub fn tailwind_to_gpui(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+// Groups of tailwind classes, like the ones given to tailwind_to_gpui
+struct TailwindClassTableInput {
+    tailwind_class_groups: Vec<Vec<LitStr>>,
+}
+
+impl Parse for TailwindClassTableInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut tailwind_class_groups = Vec::new();
+        while !input.is_empty() {
+            let content;
+            syn::bracketed!(content in input);
+            let classes = Punctuated::<LitStr, Comma>::parse_terminated(&content)?;
+            tailwind_class_groups.push(classes.into_iter().collect());
+            let _ = input.parse::<Token![,]>().ok();
+        }
+        Ok(TailwindClassTableInput {
+            tailwind_class_groups,
+        })
+    }
+}
+
+// Creates "&[("class-name", "class_name()"), ...]" with the method call tailwind_to_gpui
+// generates for each class, for tools that need the mapping as data
+#[proc_macro]
+pub fn tailwind_class_table(input: TokenStream) -> TokenStream {
+    let TailwindClassTableInput {
+        tailwind_class_groups,
+    } = parse_macro_input!(input as TailwindClassTableInput);
+
+    let entries = tailwind_class_groups.iter().flatten().map(|class| {
+        let method_call = method_call(&class.value());
+        quote! {
+            (#class, #method_call),
+        }
+    });
+
+    TokenStream::from(quote! {
+        &[#(#entries)*]
+    })
+}
+
+// GPUI method call of a tailwind class, e.g. "flex_col()" for "flex-col"
+fn method_call(class: &str) -> String {
+    // Fonts are little bit different
+    if let Some(weight) = class.strip_prefix("font-") {
+        let font_weight = match weight {
+            "thin" => "THIN",
+            "extralight" => "EXTRA_LIGHT",
+            "light" => "LIGHT",
+            "normal" => "NORMAL",
+            "medium" => "MEDIUM",
+            "semibold" => "SEMIBOLD",
+            "bold" => "BOLD",
+            "extrabold" => "EXTRA_BOLD",
+            "black" => "BLACK",
+            _ => "NORMAL",
+        };
+        return format!("font_weight(FontWeight::{})", font_weight);
+    }
+
+    // Replace "-" to "_" and "/" to "_" in class name
+    let method_name = class.replace("-", "_").replace("/", "_").replace(".", "p");
+    format!("{}()", method_name)
+}